@@ -0,0 +1,384 @@
+use crate::evm;
+use crate::msg::{AbiValue, Chain};
+use crate::state::{CALL_ALLOWLIST, TARGET_ABIS};
+use crate::ContractError;
+use cosmwasm_std::{from_json, Int256, Order, StdResult, Storage, Uint256};
+use ethabi::param_type::{Reader, Writer};
+use ethabi::{Contract, Function, Int, Param, ParamType, StateMutability, Token, Uint};
+use std::collections::BTreeMap;
+
+/// Functions of the CompetitionEth/CompetitionArb Vyper contracts, used for chains without a registered ABI.
+pub fn builtin_contract() -> Contract {
+    let winner_info = ParamType::Tuple(vec![ParamType::Address, ParamType::Uint(256)]);
+    let epoch_info = ParamType::Tuple(vec![ParamType::Uint(256); 5]);
+    let functions = [
+        ("set_paloma", vec![]),
+        ("update_compass", vec![("_new_compass", ParamType::Address)]),
+        (
+            "set_winner_list",
+            vec![("_winner_infos", ParamType::Array(Box::new(winner_info)))],
+        ),
+        ("set_active_epoch", vec![("_epoch_info", epoch_info)]),
+        ("cancel_epoch", vec![("_epoch_id", ParamType::Uint(256))]),
+        (
+            "amend_epoch",
+            vec![
+                ("_epoch_id", ParamType::Uint(256)),
+                ("_prize_amount", ParamType::Uint(256)),
+                ("_competition_end", ParamType::Uint(256)),
+            ],
+        ),
+    ];
+    #[allow(deprecated)]
+    Contract {
+        constructor: None,
+        functions: BTreeMap::from_iter(functions.into_iter().map(|(name, inputs)| {
+            (
+                name.to_string(),
+                vec![Function {
+                    name: name.to_string(),
+                    inputs: inputs
+                        .into_iter()
+                        .map(|(name, kind)| Param {
+                            name: name.to_string(),
+                            kind,
+                            internal_type: None,
+                        })
+                        .collect(),
+                    outputs: Vec::new(),
+                    constant: None,
+                    state_mutability: StateMutability::NonPayable,
+                }],
+            )
+        })),
+        events: BTreeMap::new(),
+        errors: BTreeMap::new(),
+        receive: false,
+        fallback: false,
+    }
+}
+
+/// Parses a Vyper/Solidity JSON ABI.
+///
+/// Uses `cosmwasm_std::from_json` rather than `Contract::load`, which would link serde_json's
+/// float parsing into the wasm.
+pub fn parse_abi(abi: &str) -> Result<Contract, ContractError> {
+    let contract: Contract =
+        from_json(abi.as_bytes()).map_err(|err| ContractError::InvalidAbi {
+            reason: err.to_string(),
+        })?;
+    if let Some(function) = contract
+        .functions()
+        .find(|function| !function.inputs.iter().all(|param| valid_kind(&param.kind)))
+    {
+        return Err(ContractError::InvalidAbi {
+            reason: format!("invalid parameter type in {}", function.signature()),
+        });
+    }
+    Ok(contract)
+}
+
+/// Functions the contract sends itself after its own checks; `CallTarget` must not reach them.
+pub const MANAGED_FUNCTIONS: [&str; 6] = [
+    "set_paloma",
+    "update_compass",
+    "set_winner_list",
+    "set_active_epoch",
+    "cancel_epoch",
+    "amend_epoch",
+];
+
+/// Whether `function` is, by name or selector, one of the chain's `MANAGED_FUNCTIONS`.
+pub fn is_managed(
+    storage: &dyn Storage,
+    chain: Chain,
+    function: &Function,
+) -> Result<bool, ContractError> {
+    if MANAGED_FUNCTIONS.contains(&function.name.as_str()) {
+        return Ok(true);
+    }
+    let selector = function.short_signature();
+    let contract = load_contract(storage, chain)?;
+    let builtin = builtin_contract();
+    Ok(contract
+        .functions()
+        .chain(builtin.functions())
+        .any(|managed| {
+            MANAGED_FUNCTIONS.contains(&managed.name.as_str())
+                && managed.short_signature() == selector
+        }))
+}
+
+/// The registered ABI of the chain's competition contract, or the built-in one.
+pub fn load_contract(storage: &dyn Storage, chain: Chain) -> Result<Contract, ContractError> {
+    match TARGET_ABIS.may_load(storage, chain.as_str())? {
+        Some(abi) => parse_abi(&abi),
+        None => Ok(builtin_contract()),
+    }
+}
+
+/// Encodes calldata for the named function of the chain's competition contract.
+pub fn encode_function(
+    storage: &dyn Storage,
+    chain: Chain,
+    name: &str,
+    tokens: &[Token],
+) -> Result<Vec<u8>, ContractError> {
+    let contract = load_contract(storage, chain)?;
+    let function = contract
+        .function(name)
+        .map_err(|_| ContractError::FunctionNotFound {
+            function: name.to_string(),
+        })?;
+    function
+        .encode_input(tokens)
+        .map_err(|err| ContractError::InvalidArguments {
+            reason: err.to_string(),
+        })
+}
+
+/// Parses a Solidity-style signature such as `set_entry_fee(uint256)` into a function definition.
+pub fn parse_signature(signature: &str) -> Result<Function, ContractError> {
+    let invalid = || ContractError::InvalidSignature {
+        signature: signature.to_string(),
+    };
+    let signature = signature.replace(' ', "");
+    let (name, params) = signature.split_once('(').ok_or_else(invalid)?;
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(invalid());
+    }
+    let inputs = match Reader::read(&format!("({params}")).map_err(|_| invalid())? {
+        ParamType::Tuple(inputs) => inputs,
+        _ => return Err(invalid()),
+    };
+    // `Reader` reads unknown type names as `uint8`; they show up as a different canonical form.
+    let canonical: Vec<String> = inputs.iter().map(Writer::write).collect();
+    if expand_aliases(&format!("({params}")) != format!("({})", canonical.join(","))
+        || !inputs.iter().all(valid_kind)
+    {
+        return Err(invalid());
+    }
+    #[allow(deprecated)]
+    Ok(Function {
+        name: name.to_string(),
+        inputs: inputs
+            .into_iter()
+            .map(|kind| Param {
+                name: String::new(),
+                kind,
+                internal_type: None,
+            })
+            .collect(),
+        outputs: Vec::new(),
+        constant: None,
+        state_mutability: StateMutability::NonPayable,
+    })
+}
+
+/// Canonical `name(type,...)` form of a signature, used as the allowlist key.
+pub fn canonical_signature(signature: &str) -> Result<String, ContractError> {
+    let function = parse_signature(signature)?;
+    let params: Vec<String> = function
+        .inputs
+        .iter()
+        .map(|param| Writer::write(&param.kind))
+        .collect();
+    Ok(format!("{}({})", function.name, params.join(",")))
+}
+
+/// Encodes calldata for `function` from JSON-typed arguments.
+pub fn encode_call(function: &Function, args: &[AbiValue]) -> Result<Vec<u8>, ContractError> {
+    if args.len() != function.inputs.len() {
+        return Err(ContractError::InvalidArguments {
+            reason: format!(
+                "expected {} arguments, got {}",
+                function.inputs.len(),
+                args.len()
+            ),
+        });
+    }
+    let tokens = args
+        .iter()
+        .zip(function.inputs.iter())
+        .map(|(arg, param)| tokenize(arg, &param.kind))
+        .collect::<Result<Vec<Token>, ContractError>>()?;
+    function
+        .encode_input(&tokens)
+        .map_err(|err| ContractError::InvalidArguments {
+            reason: err.to_string(),
+        })
+}
+
+/// Converts a JSON-typed value into a token of the given ABI type.
+pub fn tokenize(value: &AbiValue, kind: &ParamType) -> Result<Token, ContractError> {
+    let mismatch = || ContractError::InvalidArguments {
+        reason: format!("{value:?} is not a valid {}", Writer::write(kind)),
+    };
+    let token = match (value, kind) {
+        (AbiValue::Address(address), ParamType::Address) => {
+            Token::Address(evm::parse_address(address)?)
+        }
+        (AbiValue::Uint(value), ParamType::Uint(bits)) => {
+            let bytes = value.to_be_bytes();
+            if !fits(&bytes, *bits, false) {
+                return Err(mismatch());
+            }
+            Token::Uint(Uint::from_big_endian(&bytes))
+        }
+        (AbiValue::Int(value), ParamType::Int(bits)) => {
+            let bytes = value.to_be_bytes();
+            if !fits(&bytes, *bits, true) {
+                return Err(mismatch());
+            }
+            Token::Int(Int::from_big_endian(&bytes))
+        }
+        (AbiValue::Bool(value), ParamType::Bool) => Token::Bool(*value),
+        (AbiValue::String(value), ParamType::String) => Token::String(value.clone()),
+        (AbiValue::Bytes(value), ParamType::Bytes) => Token::Bytes(value.to_vec()),
+        (AbiValue::FixedBytes(value), ParamType::FixedBytes(size)) if value.len() == *size => {
+            Token::FixedBytes(value.to_vec())
+        }
+        (AbiValue::Array(values), ParamType::Array(inner)) => Token::Array(
+            values
+                .iter()
+                .map(|value| tokenize(value, inner))
+                .collect::<Result<Vec<Token>, ContractError>>()?,
+        ),
+        (AbiValue::Array(values), ParamType::FixedArray(inner, size)) if values.len() == *size => {
+            Token::FixedArray(
+                values
+                    .iter()
+                    .map(|value| tokenize(value, inner))
+                    .collect::<Result<Vec<Token>, ContractError>>()?,
+            )
+        }
+        (AbiValue::Tuple(values), ParamType::Tuple(kinds)) if values.len() == kinds.len() => {
+            Token::Tuple(
+                values
+                    .iter()
+                    .zip(kinds.iter())
+                    .map(|(value, kind)| tokenize(value, kind))
+                    .collect::<Result<Vec<Token>, ContractError>>()?,
+            )
+        }
+        _ => return Err(mismatch()),
+    };
+    Ok(token)
+}
+
+/// Every function a payload sent to the chain may call: the registered ABI, the built-in
+/// definitions and the `CallTarget` allowlist, in that order of precedence.
+pub fn known_functions(
+    storage: &dyn Storage,
+    chain: Chain,
+) -> Result<Vec<Function>, ContractError> {
+    let mut functions: Vec<Function> = load_contract(storage, chain)?
+        .functions()
+        .cloned()
+        .collect();
+    functions.extend(builtin_contract().functions().cloned());
+    for signature in CALL_ALLOWLIST
+        .prefix(chain.as_str())
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?
+    {
+        functions.push(parse_signature(&signature)?);
+    }
+    Ok(functions)
+}
+
+/// Finds the function matching the payload's 4-byte selector and decodes its arguments.
+pub fn decode_call(
+    functions: &[Function],
+    payload: &[u8],
+) -> Result<(Function, Vec<Token>), ContractError> {
+    let selector = payload.get(..4).ok_or(ContractError::UnknownSelector {})?;
+    let function = functions
+        .iter()
+        .find(|function| function.short_signature() == selector)
+        .ok_or(ContractError::UnknownSelector {})?;
+    let tokens =
+        function
+            .decode_input(&payload[4..])
+            .map_err(|err| ContractError::InvalidArguments {
+                reason: err.to_string(),
+            })?;
+    Ok((function.clone(), tokens))
+}
+
+/// Converts a decoded token back into its JSON-typed form.
+pub fn detokenize(token: Token) -> AbiValue {
+    match token {
+        Token::Address(address) => AbiValue::Address(evm::to_checksum(&address)),
+        Token::Uint(value) => {
+            let mut bytes = [0u8; 32];
+            value.to_big_endian(&mut bytes);
+            AbiValue::Uint(Uint256::from_be_bytes(bytes))
+        }
+        Token::Int(value) => {
+            let mut bytes = [0u8; 32];
+            value.to_big_endian(&mut bytes);
+            AbiValue::Int(Int256::from_be_bytes(bytes))
+        }
+        Token::Bool(value) => AbiValue::Bool(value),
+        Token::String(value) => AbiValue::String(value),
+        Token::Bytes(value) => AbiValue::Bytes(value.into()),
+        Token::FixedBytes(value) => AbiValue::FixedBytes(value.into()),
+        Token::Array(values) | Token::FixedArray(values) => {
+            AbiValue::Array(values.into_iter().map(detokenize).collect())
+        }
+        Token::Tuple(values) => AbiValue::Tuple(values.into_iter().map(detokenize).collect()),
+    }
+}
+
+/// `types` with the `uint` and `int` aliases spelt out as `uint256` and `int256`.
+fn expand_aliases(types: &str) -> String {
+    fn expand(word: &str) -> &str {
+        match word {
+            "uint" => "uint256",
+            "int" => "int256",
+            word => word,
+        }
+    }
+    let mut expanded = String::new();
+    let mut word = String::new();
+    for c in types.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+            continue;
+        }
+        expanded.push_str(expand(&word));
+        word.clear();
+        expanded.push(c);
+    }
+    expanded.push_str(expand(&word));
+    expanded
+}
+
+/// Whether the type exists on the EVM: (u)ints of 8 to 256 bits in steps of 8, `bytes1` to
+/// `bytes32`, and non-empty tuples and fixed arrays.
+fn valid_kind(kind: &ParamType) -> bool {
+    match kind {
+        ParamType::Uint(bits) | ParamType::Int(bits) => (8..=256).contains(bits) && bits % 8 == 0,
+        ParamType::FixedBytes(size) => (1..=32).contains(size),
+        ParamType::Array(inner) => valid_kind(inner),
+        ParamType::FixedArray(inner, size) => *size > 0 && valid_kind(inner),
+        ParamType::Tuple(kinds) => !kinds.is_empty() && kinds.iter().all(valid_kind),
+        ParamType::Address | ParamType::Bytes | ParamType::Bool | ParamType::String => true,
+    }
+}
+
+/// Whether a 256-bit big-endian value is representable in a `bits`-wide (u)int.
+fn fits(bytes: &[u8; 32], bits: usize, signed: bool) -> bool {
+    if !valid_kind(&ParamType::Uint(bits)) {
+        return false;
+    }
+    let unused = (256 - bits) / 8;
+    if unused == 0 {
+        return true;
+    }
+    let negative = signed && bytes[0] & 0x80 != 0;
+    let sign = if negative { 0xff } else { 0 };
+    bytes[..unused].iter().all(|byte| *byte == sign)
+        && (!signed || (bytes[unused] & 0x80 != 0) == negative)
+}
@@ -1,15 +1,26 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    to_json_binary, to_json_vec, Addr, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdResult, Storage, SubMsg, SubMsgResult, Timestamp, Uint256,
+};
 use cw2::set_contract_version;
 
+use crate::abi;
+use crate::dry_run::DryRunStorage;
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, GetJobIdResponse, InstantiateMsg, Metadata, PalomaMsg, QueryMsg};
-use crate::state::{State, STATE};
+use crate::evm;
+use crate::msg::DispatchStatus;
+use crate::msg::{
+    Chain, ExecuteMsg, GetJobIdResponse, InstantiateMsg, Metadata, MigrateMsg, PalomaMsg, QueryMsg,
+    RetryAction, RetryPolicy,
+};
+use crate::state::{
+    RetryState, State, CHAIN_CONFIGS, DISPATCHES, EPOCH_TEMPLATES, FEE_CONFIGS, LEGACY_STATE,
+    LEGACY_WITHDRAW_TIMESTAMP, STATE, WITHDRAW_TIMESTAMP,
+};
 use cosmwasm_std::CosmosMsg;
-use ethabi::{Contract, Function, Param, ParamType, StateMutability, Token, Uint};
-use std::collections::BTreeMap;
-use std::str::FromStr;
+use ethabi::{Token, Uint};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io::juice-bot-eth-predictor-cw";
@@ -22,8 +33,11 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    execute::validate_retry_policy(&msg.retry_policy)?;
     let state = State {
-        retry_delay: msg.retry_delay,
+        retry_policy: msg.retry_policy,
+        rollback_on_submit_failure: msg.rollback_on_submit_failure.unwrap_or(false),
+        paloma_msg_version: msg.paloma_msg_version.unwrap_or_default(),
         job_arb_id: msg.job_arb_id.clone(),
         job_eth_id: msg.job_eth_id.clone(),
         owner: info.sender.clone(),
@@ -41,28 +55,254 @@ pub fn instantiate(
         .add_attribute("job_eth_id", msg.job_eth_id))
 }
 
+/// Upgrades a contract stored before retry policies: its `retry_delay` becomes a fixed-delay
+/// policy, epoch retry timestamps become retry states with one attempt, and winner timestamps,
+/// whose keys lack the epoch of the payout, are cleared.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let mut response = Response::new().add_attribute("method", "migrate");
+    if STATE.load(deps.storage).is_err() {
+        let legacy = LEGACY_STATE.load(deps.storage)?;
+        let retry_policy = RetryPolicy {
+            base_delay: legacy.retry_delay,
+            multiplier: Decimal::one(),
+            max_delay: legacy.retry_delay,
+            max_attempts: msg.max_attempts.unwrap_or(u32::MAX),
+        };
+        execute::validate_retry_policy(&retry_policy)?;
+        STATE.save(
+            deps.storage,
+            &State {
+                retry_policy,
+                rollback_on_submit_failure: false,
+                paloma_msg_version: Default::default(),
+                job_eth_id: legacy.job_eth_id,
+                job_arb_id: legacy.job_arb_id,
+                owner: legacy.owner,
+                metadata: legacy.metadata,
+            },
+        )?;
+        let timestamps = LEGACY_WITHDRAW_TIMESTAMP
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<((String, String), Timestamp)>>>()?;
+        let mut cleared = 0u32;
+        for (key, timestamp) in timestamps {
+            if key.1 == RetryAction::SetActiveEpoch.as_str() {
+                let retry = RetryState {
+                    timestamp,
+                    attempts: 1,
+                    reopened: false,
+                };
+                WITHDRAW_TIMESTAMP.save(deps.storage, key, &retry)?;
+            } else {
+                WITHDRAW_TIMESTAMP.remove(deps.storage, key);
+                cleared += 1;
+            }
+        }
+        response = response.add_attribute("cleared_retry_keys", cleared.to_string());
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(response.add_attribute("version", CONTRACT_VERSION))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    if !msg.dry_run() {
+        return execute_msg(deps, env, info, msg);
+    }
+    let mut storage = DryRunStorage::new(deps.storage);
+    let deps = DepsMut {
+        storage: &mut storage,
+        api: deps.api,
+        querier: deps.querier,
+    };
+    let response = execute_msg(deps, env, info, msg)?;
+    let dry_run = Response::new()
+        .add_attributes(response.attributes)
+        .add_attribute("dry_run", "true");
+    Ok(match response.data {
+        Some(data) => dry_run.set_data(data),
+        None => dry_run,
+    })
+}
+
+fn execute_msg(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
 ) -> Result<Response<PalomaMsg>, ContractError> {
     match msg {
-        ExecuteMsg::SetEthPaloma {} => execute::set_eth_paloma(deps, info),
-        ExecuteMsg::UpdateEthCompass { new_compass } => {
-            execute::update_eth_compass(deps, info, new_compass)
-        }
-        ExecuteMsg::SetWinnerList { winner_infos } => {
-            execute::set_winner_list(deps, env, info, winner_infos)
+        ExecuteMsg::SetEthPaloma { .. } => execute::set_eth_paloma(deps, env, info),
+        ExecuteMsg::UpdateEthCompass { new_compass, .. } => {
+            execute::update_eth_compass(deps, env, info, new_compass)
         }
+        ExecuteMsg::SetWinnerList {
+            epoch_id,
+            winner_infos,
+            signature,
+            ..
+        } => execute::set_winner_list(deps, env, info, epoch_id, winner_infos, signature),
 
-        ExecuteMsg::SetArbPaloma {} => execute::set_arb_paloma(deps, info),
-        ExecuteMsg::UpdateArbCompass { new_compass } => {
-            execute::update_arb_compass(deps, info, new_compass)
+        ExecuteMsg::SetArbPaloma { .. } => execute::set_arb_paloma(deps, env, info),
+        ExecuteMsg::UpdateArbCompass { new_compass, .. } => {
+            execute::update_arb_compass(deps, env, info, new_compass)
+        }
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info,
+            signature,
+            ..
+        } => execute::set_active_epoch(deps, env, info, epoch_info, signature),
+        ExecuteMsg::SetActiveEpochAll {
+            epoch_info,
+            chains,
+            signature,
+            ..
+        } => execute::set_active_epoch_all(deps, env, info, epoch_info, chains, signature),
+        ExecuteMsg::BeginWinnerList { epoch_id } => {
+            execute::begin_winner_list(deps, info, epoch_id)
+        }
+        ExecuteMsg::AppendWinners { epoch_id, winners } => {
+            execute::append_winners(deps, info, epoch_id, winners)
+        }
+        ExecuteMsg::FinalizeWinnerList {
+            epoch_id,
+            expected_count,
+            expected_total,
+            ..
+        } => {
+            execute::finalize_winner_list(deps, env, info, epoch_id, expected_count, expected_total)
+        }
+        ExecuteMsg::CancelWinnerList { epoch_id } => {
+            execute::cancel_winner_list(deps, info, epoch_id)
+        }
+        ExecuteMsg::UpdateApprovalConfig {
+            approvers,
+            threshold,
+            proposal_ttl,
+        } => execute::update_approval_config(deps, env, info, approvers, threshold, proposal_ttl),
+        ExecuteMsg::ProposeWinnerList {
+            epoch_id,
+            winner_infos,
+        } => execute::propose_winner_list(deps, env, info, epoch_id, winner_infos),
+        ExecuteMsg::ApproveWinnerList { proposal_id } => {
+            execute::approve_winner_list(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::RejectWinnerList { proposal_id } => {
+            execute::reject_winner_list(deps, env, info, proposal_id)
+        }
+        ExecuteMsg::AddAttestor { pubkey } => execute::add_attestor(deps, env, info, pubkey),
+        ExecuteMsg::RemoveAttestor { pubkey } => execute::remove_attestor(deps, info, pubkey),
+        ExecuteMsg::CallTarget {
+            chain,
+            function,
+            args,
+            ..
+        } => execute::call_target(deps, env, info, chain, function, args),
+        ExecuteMsg::AllowCall { chain, function } => {
+            execute::allow_call(deps, info, chain, function)
+        }
+        ExecuteMsg::DisallowCall { chain, function } => {
+            execute::disallow_call(deps, info, chain, function)
+        }
+        ExecuteMsg::SetTargetAbi { chain, abi } => execute::set_target_abi(deps, info, chain, abi),
+        ExecuteMsg::ClearTargetAbi { chain } => execute::clear_target_abi(deps, info, chain),
+        ExecuteMsg::GrantRole { role, address } => execute::grant_role(deps, info, role, address),
+        ExecuteMsg::RevokeRole { role, address } => execute::revoke_role(deps, info, role, address),
+        ExecuteMsg::ReportExecution {
+            dispatch_id,
+            evm_tx_hash,
+            success,
+            error,
+        } => execute::report_execution(deps, env, info, dispatch_id, evm_tx_hash, success, error),
+        ExecuteMsg::Resend { dispatch_id, .. } => execute::resend(deps, env, info, dispatch_id),
+        ExecuteMsg::UpdateRetryPolicy {
+            action,
+            retry_policy,
+        } => execute::update_retry_policy(deps, info, action, retry_policy),
+        ExecuteMsg::SetRollbackOnSubmitFailure { enabled } => {
+            execute::set_rollback_on_submit_failure(deps, info, enabled)
+        }
+        ExecuteMsg::SetPalomaMsgVersion { version } => {
+            execute::set_paloma_msg_version(deps, info, version)
+        }
+        ExecuteMsg::SetJobId { chain, job_id } => execute::set_job_id(deps, info, chain, job_id),
+        ExecuteMsg::SetChainConfig {
+            chain,
+            chain_reference_id,
+            competition_contract,
+        } => execute::set_chain_config(deps, info, chain, chain_reference_id, competition_contract),
+        ExecuteMsg::SetFeeConfig {
+            chain,
+            fee_bps,
+            treasury,
+        } => execute::set_fee_config(deps, env, info, chain, fee_bps, treasury),
+        ExecuteMsg::SetEpochTemplate { chain, template } => {
+            execute::set_epoch_template(deps, info, chain, template)
+        }
+        ExecuteMsg::StartNextEpoch { chain, .. } => {
+            execute::start_next_epoch(deps, env, info, chain)
         }
-        ExecuteMsg::SetActiveEpoch { epoch_info } => {
-            execute::set_active_epoch(deps, env, info, epoch_info)
+        ExecuteMsg::FundPrizePool { chain, epoch_id } => {
+            execute::fund_prize_pool(deps, env, info, chain, epoch_id)
+        }
+        ExecuteMsg::Receive(msg) => execute::receive(deps, env, info, msg),
+        ExecuteMsg::ScheduleEpoch { epoch_info, chain } => {
+            execute::schedule_epoch(deps, env, info, chain.unwrap_or(Chain::Arb), epoch_info)
+        }
+        ExecuteMsg::CloseEpoch { chain, epoch_id } => {
+            execute::close_epoch(deps, env, info, chain, epoch_id)
+        }
+        ExecuteMsg::AmendEpoch {
+            epoch_id,
+            prize_amount,
+            competition_end,
+            chain,
+            ..
+        } => execute::amend_epoch(
+            deps,
+            env,
+            info,
+            chain.unwrap_or(Chain::Arb),
+            epoch_id,
+            prize_amount,
+            competition_end,
+        ),
+        ExecuteMsg::CancelEpoch {
+            chain,
+            epoch_id,
+            reason,
+            ..
+        } => execute::cancel_epoch(deps, env, info, chain, epoch_id, reason),
+        ExecuteMsg::RefundSponsorship {
+            chain,
+            epoch_id,
+            token,
+        } => execute::refund_sponsorship(deps, env, info, chain, epoch_id, token),
+        ExecuteMsg::SweepSponsorships {
+            chain,
+            epoch_id,
+            token,
+            recipient,
+        } => execute::sweep_sponsorships(deps, env, info, chain, epoch_id, token, recipient),
+        ExecuteMsg::ResetRetry {
+            action,
+            key,
+            chain,
+            epoch_id,
+        } => execute::reset_retry(deps, info, action, key, chain, epoch_id),
+        ExecuteMsg::Tick {} => execute::tick(deps, env),
+        ExecuteMsg::SetSeason {
+            season_id,
+            season_info,
+        } => execute::set_season(deps, info, season_id, season_info),
+        ExecuteMsg::SettleSeason { season_id, .. } => {
+            execute::settle_season(deps, env, info, season_id)
         }
     }
 }
@@ -71,53 +311,67 @@ pub mod execute {
     use super::*;
     use crate::msg::EpochInfo;
     use crate::msg::WinnerInfo;
-    use crate::state::WITHDRAW_TIMESTAMP;
-    use crate::ContractError::{AllPending, Unauthorized};
+    use crate::msg::{
+        AbiValue, Chain, ChainConfig, Cw20HookMsg, DispatchData, DispatchStatus, EpochAmendment,
+        EpochStatus, EpochTemplate, FeeConfig, PalomaMsgV2, PalomaMsgVersion, PlayerStats,
+        ProposalAction, ProposalStatus, RetryAction, RetryPolicy, RetryStatus, Role, SeasonInfo,
+        SeasonStanding, SendTx,
+    };
+    use crate::state::RetryState;
+    use crate::state::{player_stats, Claim, ALL_CHAINS, EPOCH_CLAIMS, EPOCH_FEES, FEE_TOTALS};
+    use crate::state::{
+        ApprovalConfig, Dispatch, Epoch, Season, WinnerListProposal, WinnerListStaging,
+        APPROVAL_CONFIG, ATTESTORS, CALL_ALLOWLIST, CHAIN_CONFIGS, DISPATCHES, DISPATCH_COUNT,
+        EPOCHS, EPOCH_QUEUE, LATEST_EPOCHS, PRIZE_FUNDING, PROPOSALS, PROPOSAL_COUNT,
+        RETRY_POLICIES, ROLES, SEASONS, SPONSORSHIPS, STAGED_WINNERS, TARGET_ABIS,
+        USED_ATTESTATIONS, WINNER_LIST_STAGING, WITHDRAW_TIMESTAMP,
+    };
+    use crate::ContractError::{
+        AllPending, AlreadyVoted, ApprovalNotEnabled, ApprovalRequired, AttestationUsed,
+        CallNotAllowed, ChainNotConfigured, DispatchConfirmed, DispatchNotPending,
+        DispatchSuperseded, DuplicateWinner, EpochCancelled, EpochIdRequired, EpochInfoMismatch,
+        EpochNotActive, EpochNotCancelled, EpochNotFundable, EpochNotPaid, EpochOutOfOrder,
+        EpochOverlap, EpochTemplateNotConfigured, InvalidAmendment, InvalidAttestation,
+        InvalidEpochTemplate, InvalidEpochTransition, InvalidEpochWindow, InvalidFee, InvalidFunds,
+        InvalidPubkey, InvalidRetryPolicy, InvalidSeason, InvalidThreshold, JobNotConfigured,
+        ManagedFunction, NoChainsSelected, NothingToRefund, PrizePoolExceeded, ProposalClosed,
+        ProposalExpired, RetryExhausted, SeasonNoPoints, SeasonNotEnded, SeasonNotReady,
+        SeasonSettled, SkywayNotSupported, StagingExists, StagingMismatch, StagingNotFound,
+        Unauthorized,
+    };
+    use cosmwasm_std::{from_json, Api, Decimal, Empty, Order, Uint128, WasmMsg};
+    use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+    use cw_storage_plus::Bound;
     use ethabi::Address;
+    use sha2::{Digest, Sha256};
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
 
     pub fn set_eth_paloma(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
     ) -> Result<Response<PalomaMsg>, ContractError> {
         let state = STATE.load(deps.storage)?;
         if state.owner != info.sender {
             return Err(Unauthorized {});
         }
-        #[allow(deprecated)]
-        let contract: Contract = Contract {
-            constructor: None,
-            functions: BTreeMap::from_iter(vec![(
-                "set_paloma".to_string(),
-                vec![Function {
-                    name: "set_paloma".to_string(),
-                    inputs: vec![],
-                    outputs: Vec::new(),
-                    constant: None,
-                    state_mutability: StateMutability::NonPayable,
-                }],
-            )]),
-            events: BTreeMap::new(),
-            errors: BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        };
-        Ok(Response::new()
-            .add_message(CosmosMsg::Custom(PalomaMsg {
-                job_id: state.job_eth_id,
-                payload: Binary(
-                    contract
-                        .function("set_paloma")
-                        .unwrap()
-                        .encode_input(&[])
-                        .unwrap(),
-                ),
-                metadata: state.metadata,
-            }))
-            .add_attribute("action", "set_paloma"))
+        let payload = abi::encode_function(deps.storage, Chain::Eth, "set_paloma", &[])?;
+        dispatch(
+            deps.storage,
+            &env,
+            state,
+            Chain::Eth,
+            "set_paloma",
+            payload,
+            vec![],
+            vec![],
+        )
     }
 
     pub fn update_eth_compass(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
         new_compass: String,
     ) -> Result<Response<PalomaMsg>, ContractError> {
@@ -125,364 +379,2944 @@ pub mod execute {
         if state.owner != info.sender {
             return Err(Unauthorized {});
         }
-        let new_compass_address: Address = Address::from_str(new_compass.as_str()).unwrap();
-        #[allow(deprecated)]
-        let contract: Contract = Contract {
-            constructor: None,
-            functions: BTreeMap::from_iter(vec![(
-                "update_compass".to_string(),
-                vec![Function {
-                    name: "update_compass".to_string(),
-                    inputs: vec![Param {
-                        name: "_new_compass".to_string(),
-                        kind: ParamType::Address,
-                        internal_type: None,
-                    }],
-                    outputs: Vec::new(),
-                    constant: None,
-                    state_mutability: StateMutability::NonPayable,
-                }],
-            )]),
-            events: BTreeMap::new(),
-            errors: BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        };
+        let new_compass_address: Address = evm::parse_address(&new_compass)?;
 
-        Ok(Response::new()
-            .add_message(CosmosMsg::Custom(PalomaMsg {
-                job_id: state.job_eth_id,
-                payload: Binary(
-                    contract
-                        .function("update_compass")
-                        .unwrap()
-                        .encode_input(&[Token::Address(new_compass_address)])
-                        .unwrap(),
-                ),
-                metadata: state.metadata,
-            }))
-            .add_attribute("action", "update_compass"))
+        let payload = abi::encode_function(
+            deps.storage,
+            Chain::Eth,
+            "update_compass",
+            &[Token::Address(new_compass_address)],
+        )?;
+        Ok(dispatch(
+            deps.storage,
+            &env,
+            state,
+            Chain::Eth,
+            "update_compass",
+            payload,
+            vec![],
+            vec![],
+        )?
+        .add_attribute("new_compass", evm::to_checksum(&new_compass_address)))
     }
 
-    #[allow(clippy::vec_init_then_push)]
     pub fn set_winner_list(
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
+        epoch_id: Uint256,
         winner_infos: Vec<WinnerInfo>,
+        signature: Option<Binary>,
     ) -> Result<Response<PalomaMsg>, ContractError> {
         let state = STATE.load(deps.storage)?;
-        if state.owner != info.sender {
+        let winner_infos = normalize_winner_infos(winner_infos)?;
+        if let Some(signature) = signature {
+            let payload = Token::Tuple(vec![
+                Token::Uint(Uint::from_big_endian(&epoch_id.to_be_bytes())),
+                Token::Array(
+                    winner_infos
+                        .iter()
+                        .map(attested_winner_token)
+                        .collect::<Result<Vec<Token>, ContractError>>()?,
+                ),
+            ]);
+            verify_attestation(
+                deps.storage,
+                deps.api,
+                &env,
+                "set_winner_list",
+                payload,
+                &signature,
+            )?;
+        } else if state.owner != info.sender {
             return Err(Unauthorized {});
         }
-        #[allow(deprecated)]
-        let contract: Contract = Contract {
-            constructor: None,
-            functions: BTreeMap::from_iter(vec![(
-                "set_winner_list".to_string(),
-                vec![Function {
-                    name: "set_winner_list".to_string(),
-                    inputs: vec![Param {
-                        name: "_winner_infos".to_string(),
-                        kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
-                            ParamType::Address,
-                            ParamType::Uint(256),
-                        ]))),
-                        internal_type: None,
-                    }],
-                    outputs: Vec::new(),
-                    constant: None,
-                    state_mutability: StateMutability::NonPayable,
-                }],
-            )]),
-            events: BTreeMap::new(),
-            errors: BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        };
-
-        let mut token_winner_info: Vec<Token> = vec![];
-        let retry_delay: u64 = state.retry_delay;
-
-        for winner_info in winner_infos {
-            if let Some(timestamp) = WITHDRAW_TIMESTAMP.may_load(
-                deps.storage,
-                (winner_info.winner.to_owned(), "set_winner".to_string()),
-            )? {
-                if timestamp.plus_seconds(retry_delay).lt(&env.block.time) {
-                    let mut token_winner_info_element: Vec<Token> = vec![];
-                    token_winner_info_element.push(Token::Address(
-                        Address::from_str(winner_info.winner.as_str()).unwrap(),
-                    ));
-                    token_winner_info_element.push(Token::Uint(Uint::from_big_endian(
-                        &winner_info.claimable_amount.to_be_bytes(),
-                    )));
-                    token_winner_info.push(Token::Tuple(token_winner_info_element));
-                    WITHDRAW_TIMESTAMP.save(
-                        deps.storage,
-                        (winner_info.winner.to_owned(), "set_winner".to_string()),
-                        &env.block.time,
-                    )?;
-                }
-            } else {
-                let mut token_winner_info_element: Vec<Token> = vec![];
-                token_winner_info_element.push(Token::Address(
-                    Address::from_str(winner_info.winner.as_str()).unwrap(),
-                ));
-                token_winner_info_element.push(Token::Uint(Uint::from_big_endian(
-                    &winner_info.claimable_amount.to_be_bytes(),
-                )));
-                token_winner_info.push(Token::Tuple(token_winner_info_element));
-                WITHDRAW_TIMESTAMP.save(
-                    deps.storage,
-                    (winner_info.winner.to_owned(), "set_winner".to_string()),
-                    &env.block.time,
-                )?;
-            }
+        // Attestors are registered by the owner, so signed lists need approval too: once approvals
+        // are enabled, lists only go out through `ProposeWinnerList`.
+        if approval_enabled(deps.storage)? {
+            return Err(ApprovalRequired {});
         }
-        let token_winners_info: Vec<Token> = vec![Token::Array(token_winner_info)];
+        Ok(
+            dispatch_winner_list(deps, &env, state, epoch_id, winner_infos)?
+                .add_attribute("epoch_id", epoch_id),
+        )
+    }
 
+    pub fn begin_winner_list(
+        deps: DepsMut,
+        info: MessageInfo,
+        epoch_id: Uint256,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let key = epoch_id.to_string();
+        if WINNER_LIST_STAGING.has(deps.storage, key.clone()) {
+            return Err(StagingExists {});
+        }
+        WINNER_LIST_STAGING.save(
+            deps.storage,
+            key,
+            &WinnerListStaging {
+                count: 0,
+                total: Uint256::zero(),
+            },
+        )?;
         Ok(Response::new()
-            .add_message(CosmosMsg::Custom(PalomaMsg {
-                job_id: state.job_eth_id,
-                payload: Binary(
-                    contract
-                        .function("set_winner_list")
-                        .unwrap()
-                        .encode_input(token_winners_info.as_slice())
-                        .unwrap(),
-                ),
-                metadata: state.metadata,
-            }))
-            .add_attribute("action", "set_winner_list"))
+            .add_attribute("action", "begin_winner_list")
+            .add_attribute("epoch_id", epoch_id))
     }
 
-    pub fn set_arb_paloma(
+    pub fn append_winners(
         deps: DepsMut,
         info: MessageInfo,
+        epoch_id: Uint256,
+        winners: Vec<WinnerInfo>,
     ) -> Result<Response<PalomaMsg>, ContractError> {
         let state = STATE.load(deps.storage)?;
         if state.owner != info.sender {
             return Err(Unauthorized {});
         }
-        #[allow(deprecated)]
-        let contract: Contract = Contract {
-            constructor: None,
-            functions: BTreeMap::from_iter(vec![(
-                "set_paloma".to_string(),
-                vec![Function {
-                    name: "set_paloma".to_string(),
-                    inputs: vec![],
-                    outputs: Vec::new(),
-                    constant: None,
-                    state_mutability: StateMutability::NonPayable,
-                }],
-            )]),
-            events: BTreeMap::new(),
-            errors: BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        };
+        let key = epoch_id.to_string();
+        let mut staging = WINNER_LIST_STAGING
+            .may_load(deps.storage, key.clone())?
+            .ok_or(StagingNotFound {})?;
+        let winners = normalize_winner_infos(winners)?;
+        let appended = winners.len();
+        for winner_info in winners {
+            staging.total = staging.total.checked_add(winner_info.claimable_amount)?;
+            STAGED_WINNERS.save(deps.storage, (key.clone(), staging.count), &winner_info)?;
+            staging.count += 1;
+        }
+        WINNER_LIST_STAGING.save(deps.storage, key, &staging)?;
         Ok(Response::new()
-            .add_message(CosmosMsg::Custom(PalomaMsg {
-                job_id: state.job_arb_id,
-                payload: Binary(
-                    contract
-                        .function("set_paloma")
-                        .unwrap()
-                        .encode_input(&[])
-                        .unwrap(),
-                ),
-                metadata: state.metadata,
-            }))
-            .add_attribute("action", "set_paloma"))
+            .add_attribute("action", "append_winners")
+            .add_attribute("epoch_id", epoch_id)
+            .add_attribute("appended", appended.to_string())
+            .add_attribute("count", staging.count.to_string()))
     }
 
-    pub fn update_arb_compass(
+    pub fn finalize_winner_list(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
-        new_compass: String,
+        epoch_id: Uint256,
+        expected_count: u32,
+        expected_total: Uint256,
     ) -> Result<Response<PalomaMsg>, ContractError> {
         let state = STATE.load(deps.storage)?;
         if state.owner != info.sender {
             return Err(Unauthorized {});
         }
-        let new_compass_address: Address = Address::from_str(new_compass.as_str()).unwrap();
-        #[allow(deprecated)]
-        let contract: Contract = Contract {
-            constructor: None,
-            functions: BTreeMap::from_iter(vec![(
-                "update_compass".to_string(),
-                vec![Function {
-                    name: "update_compass".to_string(),
-                    inputs: vec![Param {
-                        name: "_new_compass".to_string(),
-                        kind: ParamType::Address,
-                        internal_type: None,
-                    }],
-                    outputs: Vec::new(),
-                    constant: None,
-                    state_mutability: StateMutability::NonPayable,
-                }],
-            )]),
-            events: BTreeMap::new(),
-            errors: BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        };
+        let key = epoch_id.to_string();
+        let staging = WINNER_LIST_STAGING
+            .may_load(deps.storage, key.clone())?
+            .ok_or(StagingNotFound {})?;
+        if staging.count != expected_count || staging.total != expected_total {
+            return Err(StagingMismatch {
+                count: staging.count,
+                total: staging.total,
+            });
+        }
+        let winner_infos = take_staged_winners(deps.storage, key, staging.count)?;
+        if approval_enabled(deps.storage)? {
+            return create_proposal(
+                deps,
+                &env,
+                epoch_id,
+                winner_infos,
+                ProposalAction::SetWinnerList,
+            );
+        }
+        Ok(
+            dispatch_winner_list(deps, &env, state, epoch_id, winner_infos)?
+                .add_attribute("epoch_id", epoch_id),
+        )
+    }
 
+    pub fn cancel_winner_list(
+        deps: DepsMut,
+        info: MessageInfo,
+        epoch_id: Uint256,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let key = epoch_id.to_string();
+        let staging = WINNER_LIST_STAGING
+            .may_load(deps.storage, key.clone())?
+            .ok_or(StagingNotFound {})?;
+        take_staged_winners(deps.storage, key, staging.count)?;
         Ok(Response::new()
-            .add_message(CosmosMsg::Custom(PalomaMsg {
-                job_id: state.job_arb_id,
-                payload: Binary(
-                    contract
-                        .function("update_compass")
-                        .unwrap()
-                        .encode_input(&[Token::Address(new_compass_address)])
-                        .unwrap(),
-                ),
-                metadata: state.metadata,
-            }))
-            .add_attribute("action", "update_compass"))
+            .add_attribute("action", "cancel_winner_list")
+            .add_attribute("epoch_id", epoch_id))
     }
 
-    #[allow(clippy::vec_init_then_push)]
-    pub fn set_active_epoch(
+    pub fn update_approval_config(
         deps: DepsMut,
         env: Env,
         info: MessageInfo,
-        epoch_info: EpochInfo,
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl: u64,
     ) -> Result<Response<PalomaMsg>, ContractError> {
         let state = STATE.load(deps.storage)?;
         if state.owner != info.sender {
             return Err(Unauthorized {});
         }
-        #[allow(deprecated)]
-        let contract: Contract = Contract {
-            constructor: None,
-            functions: BTreeMap::from_iter(vec![(
-                "set_active_epoch".to_string(),
-                vec![Function {
-                    name: "set_active_epoch".to_string(),
-                    inputs: vec![Param {
-                        name: "_epoch_info".to_string(),
-                        kind: ParamType::Tuple(vec![
-                            ParamType::Uint(256),
-                            ParamType::Uint(256),
-                            ParamType::Uint(256),
-                            ParamType::Uint(256),
-                            ParamType::Uint(256),
-                        ]),
-                        internal_type: None,
-                    }],
-                    outputs: Vec::new(),
-                    constant: None,
-                    state_mutability: StateMutability::NonPayable,
-                }],
-            )]),
-            events: BTreeMap::new(),
-            errors: BTreeMap::new(),
-            receive: false,
-            fallback: false,
-        };
+        let config = approval_config(deps.as_ref(), approvers, threshold, proposal_ttl)?;
+        // The current approvers vote on who approves next.
+        if approval_enabled(deps.storage)? {
+            let action = ProposalAction::UpdateApprovalConfig {
+                approvers: config.approvers.into_iter().map(String::from).collect(),
+                threshold,
+                proposal_ttl,
+            };
+            return create_proposal(deps, &env, Uint256::zero(), vec![], action);
+        }
+        APPROVAL_CONFIG.save(deps.storage, &config)?;
+        Ok(Response::new()
+            .add_attribute("action", "update_approval_config")
+            .add_attribute("threshold", threshold.to_string()))
+    }
 
-        let mut token_epoch_info_element: Vec<Token> = vec![];
-        let retry_delay: u64 = state.retry_delay;
-        if let Some(timestamp) = WITHDRAW_TIMESTAMP.may_load(
-            deps.storage,
-            (
-                epoch_info.epoch_id.to_string(),
-                "set_active_epoch".to_string(),
-            ),
-        )? {
-            if timestamp.plus_seconds(retry_delay).lt(&env.block.time) {
-                token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                    &epoch_info.epoch_id.to_be_bytes(),
-                )));
-                token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                    &epoch_info.competition_start.to_be_bytes(),
-                )));
-                token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                    &epoch_info.competition_end.to_be_bytes(),
-                )));
-                token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                    &epoch_info.entry_cnt.to_be_bytes(),
-                )));
-                token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                    &epoch_info.prize_amount.to_be_bytes(),
-                )));
-                WITHDRAW_TIMESTAMP.save(
-                    deps.storage,
-                    (
-                        epoch_info.epoch_id.to_string(),
-                        "set_active_epoch".to_string(),
-                    ),
-                    &env.block.time,
-                )?;
+    /// Validated approval config with the approvers deduplicated.
+    fn approval_config(
+        deps: Deps,
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl: u64,
+    ) -> Result<ApprovalConfig, ContractError> {
+        let mut approver_addrs: Vec<Addr> = vec![];
+        for approver in approvers {
+            let approver_addr = deps.api.addr_validate(&approver)?;
+            if !approver_addrs.contains(&approver_addr) {
+                approver_addrs.push(approver_addr);
             }
+        }
+        if threshold as usize > approver_addrs.len() {
+            return Err(InvalidThreshold {});
+        }
+        Ok(ApprovalConfig {
+            approvers: approver_addrs,
+            threshold,
+            proposal_ttl,
+        })
+    }
+
+    pub fn propose_winner_list(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        epoch_id: Uint256,
+        winner_infos: Vec<WinnerInfo>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        if !approval_enabled(deps.storage)? {
+            return Err(ApprovalNotEnabled {});
+        }
+        let winner_infos = normalize_winner_infos(winner_infos)?;
+        check_unique_winners(&winner_infos)?;
+        create_proposal(
+            deps,
+            &env,
+            epoch_id,
+            winner_infos,
+            ProposalAction::SetWinnerList,
+        )
+    }
+
+    pub fn approve_winner_list(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        proposal_id: u64,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let config = APPROVAL_CONFIG.load(deps.storage)?;
+        let mut proposal = load_open_proposal(deps.as_ref(), &env, &info, &config, proposal_id)?;
+        proposal.approvals.push(info.sender.clone());
+        let approvals = count_votes(&proposal.approvals, &config);
+        let response = if approvals >= config.threshold {
+            proposal.status = ProposalStatus::Executed;
+            PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+            execute_proposal(deps, &env, proposal)?
         } else {
-            token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                &epoch_info.epoch_id.to_be_bytes(),
-            )));
-            token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                &epoch_info.competition_start.to_be_bytes(),
-            )));
-            token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                &epoch_info.competition_end.to_be_bytes(),
-            )));
-            token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                &epoch_info.entry_cnt.to_be_bytes(),
-            )));
-            token_epoch_info_element.push(Token::Uint(Uint::from_big_endian(
-                &epoch_info.prize_amount.to_be_bytes(),
-            )));
-            WITHDRAW_TIMESTAMP.save(
+            PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+            Response::new().add_attribute("action", "approve_winner_list")
+        };
+        Ok(response
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("approver", info.sender)
+            .add_attribute("approvals", approvals.to_string()))
+    }
+
+    pub fn reject_winner_list(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        proposal_id: u64,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let config = APPROVAL_CONFIG.load(deps.storage)?;
+        let mut proposal = load_open_proposal(deps.as_ref(), &env, &info, &config, proposal_id)?;
+        proposal.rejections.push(info.sender.clone());
+        let rejections = count_votes(&proposal.rejections, &config);
+        // Once enough approvers have rejected, the threshold can no longer be reached.
+        if rejections > config.approvers.len() as u32 - config.threshold {
+            proposal.status = ProposalStatus::Rejected;
+        }
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+        Ok(Response::new()
+            .add_attribute("action", "reject_winner_list")
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("approver", info.sender)
+            .add_attribute("rejections", rejections.to_string()))
+    }
+
+    /// Carries out an approved proposal.
+    fn execute_proposal(
+        deps: DepsMut,
+        env: &Env,
+        proposal: WinnerListProposal,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        match proposal.action {
+            ProposalAction::SetWinnerList => Ok(dispatch_winner_list(
+                deps,
+                env,
+                state,
+                proposal.epoch_id,
+                proposal.winner_infos,
+            )?
+            .add_attribute("epoch_id", proposal.epoch_id)),
+            ProposalAction::UpdateApprovalConfig {
+                approvers,
+                threshold,
+                proposal_ttl,
+            } => {
+                let config = approval_config(deps.as_ref(), approvers, threshold, proposal_ttl)?;
+                APPROVAL_CONFIG.save(deps.storage, &config)?;
+                Ok(Response::new()
+                    .add_attribute("action", "update_approval_config")
+                    .add_attribute("threshold", threshold.to_string()))
+            }
+            ProposalAction::CallTarget {
+                chain,
+                function,
+                args,
+            } => send_target_call(deps.storage, env, state, chain, &function, &args),
+            ProposalAction::AddAttestor { pubkey } => save_attestor(deps.storage, pubkey),
+            ProposalAction::SettleSeason { season_id } => {
+                pay_season(deps.storage, env, state, season_id, proposal.winner_infos)
+            }
+            ProposalAction::SetFeeConfig {
+                chain,
+                fee_bps,
+                treasury,
+            } => save_fee_config(deps.storage, chain, fee_bps, treasury),
+        }
+    }
+
+    fn approval_enabled(storage: &dyn Storage) -> StdResult<bool> {
+        Ok(APPROVAL_CONFIG
+            .may_load(storage)?
+            .is_some_and(|config| config.threshold > 0))
+    }
+
+    fn create_proposal(
+        deps: DepsMut,
+        env: &Env,
+        epoch_id: Uint256,
+        winner_infos: Vec<WinnerInfo>,
+        action: ProposalAction,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let config = APPROVAL_CONFIG.load(deps.storage)?;
+        let proposal_id = PROPOSAL_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+        let hash = match action {
+            ProposalAction::SetWinnerList => to_json_vec(&(epoch_id, &winner_infos))?,
+            ProposalAction::SettleSeason { .. } => to_json_vec(&(&action, &winner_infos))?,
+            _ => to_json_vec(&action)?,
+        };
+        let hash = Binary(Sha256::digest(hash).to_vec());
+        let response = match &action {
+            ProposalAction::SetWinnerList => Response::new()
+                .add_attribute("action", "propose_winner_list")
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("epoch_id", epoch_id),
+            action => Response::new()
+                .add_attribute("action", "propose")
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("proposal_action", action.as_str()),
+        };
+        PROPOSALS.save(
+            deps.storage,
+            proposal_id,
+            &WinnerListProposal {
+                action,
+                epoch_id,
+                winner_infos,
+                hash: hash.clone(),
+                approvals: vec![],
+                rejections: vec![],
+                expires_at: env.block.time.plus_seconds(config.proposal_ttl),
+                status: ProposalStatus::Pending,
+            },
+        )?;
+        PROPOSAL_COUNT.save(deps.storage, &proposal_id)?;
+        Ok(response.add_attribute("hash", hash.to_base64()))
+    }
+
+    /// Loads a proposal the sender may still vote on.
+    fn load_open_proposal(
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+        config: &ApprovalConfig,
+        proposal_id: u64,
+    ) -> Result<WinnerListProposal, ContractError> {
+        if !config.approvers.contains(&info.sender) {
+            return Err(Unauthorized {});
+        }
+        let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        if proposal.status != ProposalStatus::Pending {
+            return Err(ProposalClosed {});
+        }
+        if proposal.expires_at <= env.block.time {
+            return Err(ProposalExpired {});
+        }
+        if proposal.approvals.contains(&info.sender) || proposal.rejections.contains(&info.sender) {
+            return Err(AlreadyVoted {});
+        }
+        Ok(proposal)
+    }
+
+    /// Counts the votes cast by addresses that are still approvers.
+    fn count_votes(votes: &[Addr], config: &ApprovalConfig) -> u32 {
+        votes
+            .iter()
+            .filter(|voter| config.approvers.contains(voter))
+            .count() as u32
+    }
+
+    /// Loads and removes every staged winner for the epoch, together with its staging record.
+    fn take_staged_winners(
+        storage: &mut dyn Storage,
+        key: String,
+        count: u32,
+    ) -> Result<Vec<WinnerInfo>, ContractError> {
+        let mut winner_infos: Vec<WinnerInfo> = Vec::with_capacity(count as usize);
+        for index in 0..count {
+            winner_infos.push(STAGED_WINNERS.load(storage, (key.clone(), index))?);
+            STAGED_WINNERS.remove(storage, (key.clone(), index));
+        }
+        WINNER_LIST_STAGING.remove(storage, key);
+        Ok(winner_infos)
+    }
+
+    fn dispatch_winner_list(
+        deps: DepsMut,
+        env: &Env,
+        state: State,
+        epoch_id: Uint256,
+        winner_infos: Vec<WinnerInfo>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        check_unique_winners(&winner_infos)?;
+        let settled = payout_epochs(deps.storage, env, epoch_id, &winner_infos)?;
+        for chain in &settled {
+            transition_epoch(
                 deps.storage,
+                env,
+                *chain,
+                epoch_id,
+                EpochStatus::Settled,
+                None,
+            )?;
+        }
+        let fees = winner_list_fees(deps.storage, epoch_id, &winner_infos)?;
+        let (responses, dispatch_ids, skipped) = dispatch_winners(
+            deps.storage,
+            env,
+            state,
+            winner_infos.clone(),
+            fees.clone(),
+            Some(epoch_id),
+        )?;
+        record_claims(
+            deps.storage,
+            epoch_id,
+            &winner_infos,
+            &skipped,
+            &fees,
+            &dispatch_ids,
+        )?;
+        let mut epochs: Vec<(Chain, Epoch)> = vec![];
+        for chain in settled {
+            let epoch = EPOCHS.load(deps.storage, (chain.as_str(), epoch_id.to_string()))?;
+            epochs.push((chain, epoch));
+        }
+        // A chain's dispatch is kept on its own epoch, or on the first settled one if the chain
+        // has none.
+        for dispatch_id in dispatch_ids {
+            set_dispatch_epoch(deps.storage, dispatch_id, epoch_id)?;
+            let chain = DISPATCHES.load(deps.storage, dispatch_id)?.chain;
+            let index = epochs.iter().position(|(c, _)| *c == chain).unwrap_or(0);
+            epochs[index].1.payout_dispatches.push(dispatch_id);
+        }
+        // Every settled epoch keeps the winners sent. Ranks come from the first list a winner is
+        // sent in on a chain; resent lists keep them, and skipped winners count once they are
+        // sent.
+        for (index, winner_info) in winner_infos.iter().enumerate() {
+            if skipped.contains(&index)
+                || epochs[0].1.winners.iter().any(|winner| {
+                    winner.winner == winner_info.winner
+                        && winner.chain.unwrap_or(Chain::Eth)
+                            == winner_info.chain.unwrap_or(Chain::Eth)
+                })
+            {
+                continue;
+            }
+            let rank = epochs[0].1.winners.len() as u32 + 1;
+            record_win(deps.storage, winner_info, rank, epoch_id)?;
+            for (_, epoch) in epochs.iter_mut() {
+                epoch.winners.push(winner_info.clone());
+            }
+        }
+        for (chain, epoch) in epochs {
+            EPOCHS.save(deps.storage, (chain.as_str(), epoch_id.to_string()), &epoch)?;
+        }
+        Ok(merge_responses("set_winner_list", responses)?)
+    }
+
+    /// Fails with `DuplicateWinner` if a winner is listed twice for the same chain, since a
+    /// second entry would be left out by the retry gate of the first.
+    fn check_unique_winners(winner_infos: &[WinnerInfo]) -> Result<(), ContractError> {
+        let mut seen: Vec<(Chain, &str)> = vec![];
+        for winner_info in winner_infos {
+            let key = (
+                winner_info.chain.unwrap_or(Chain::Eth),
+                winner_info.winner.as_str(),
+            );
+            if seen.contains(&key) {
+                return Err(DuplicateWinner {
+                    winner: winner_info.winner.clone(),
+                    chain: key.0.as_str().to_string(),
+                });
+            }
+            seen.push(key);
+        }
+        Ok(())
+    }
+
+    /// Epochs a winner list settles: the epoch of each chain it pays on, or the `arb` epoch
+    /// when none of those chains has one.
+    fn payout_epochs(
+        storage: &dyn Storage,
+        env: &Env,
+        epoch_id: Uint256,
+        winner_infos: &[WinnerInfo],
+    ) -> Result<Vec<Chain>, ContractError> {
+        let mut chains: Vec<Chain> = winner_infos
+            .iter()
+            .map(|winner_info| winner_info.chain.unwrap_or(Chain::Eth))
+            .collect();
+        if chains.is_empty() {
+            chains.push(Chain::Eth);
+        }
+        chains.sort_by_key(|chain| chain.as_str());
+        chains.dedup();
+        let mut settled: Vec<Chain> = vec![];
+        for chain in chains {
+            match epoch_status(storage, env, chain, epoch_id)? {
+                Some(EpochStatus::Cancelled) => return Err(EpochCancelled {}),
+                Some(_) => settled.push(chain),
+                None => {}
+            }
+        }
+        if settled.is_empty() {
+            if epoch_status(storage, env, Chain::Arb, epoch_id)? == Some(EpochStatus::Cancelled) {
+                return Err(EpochCancelled {});
+            }
+            settled.push(Chain::Arb);
+        }
+        Ok(settled)
+    }
+
+    /// Chain of the epoch whose payout includes `dispatch_id`.
+    fn payout_epoch_chain(
+        storage: &dyn Storage,
+        epoch_id: Uint256,
+        dispatch_id: u64,
+    ) -> StdResult<Option<Chain>> {
+        for chain in [Chain::Eth, Chain::Arb] {
+            if EPOCHS
+                .may_load(storage, (chain.as_str(), epoch_id.to_string()))?
+                .is_some_and(|epoch| epoch.payout_dispatches.contains(&dispatch_id))
+            {
+                return Ok(Some(chain));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Adds a win to the player's stats over every chain and on the chain it is paid on.
+    fn record_win(
+        storage: &mut dyn Storage,
+        winner_info: &WinnerInfo,
+        rank: u32,
+        epoch_id: Uint256,
+    ) -> Result<(), ContractError> {
+        let chain = winner_info.chain.unwrap_or(Chain::Eth);
+        let player_stats = player_stats();
+        for (bucket, chain) in [(ALL_CHAINS, None), (chain.as_str(), Some(chain))] {
+            let key = (bucket, winner_info.winner.as_str());
+            let mut stats = player_stats.may_load(storage, key)?.unwrap_or(PlayerStats {
+                address: winner_info.winner.clone(),
+                chain,
+                total_won: Uint256::zero(),
+                wins: 0,
+                best_rank: rank,
+                last_win_epoch: epoch_id,
+            });
+            stats.total_won = stats.total_won.checked_add(winner_info.claimable_amount)?;
+            stats.wins += 1;
+            stats.best_rank = stats.best_rank.min(rank);
+            stats.last_win_epoch = stats.last_win_epoch.max(epoch_id);
+            player_stats.save(storage, key, &stats)?;
+        }
+        Ok(())
+    }
+
+    /// Treasury entries for the protocol fee of each chain the list pays on with a
+    /// `FeeConfig`, unless a dispatch the Paloma module accepted already carries it.
+    fn winner_list_fees(
+        storage: &dyn Storage,
+        epoch_id: Uint256,
+        winner_infos: &[WinnerInfo],
+    ) -> Result<Vec<WinnerInfo>, ContractError> {
+        let mut chains: Vec<Chain> = winner_infos
+            .iter()
+            .map(|winner_info| winner_info.chain.unwrap_or(Chain::Eth))
+            .collect();
+        chains.sort_by_key(|chain| chain.as_str());
+        chains.dedup();
+        let mut fees: Vec<WinnerInfo> = vec![];
+        for chain in chains {
+            let Some(fee_config) = FEE_CONFIGS.may_load(storage, chain.as_str())? else {
+                continue;
+            };
+            let (_, fee) = epoch_prize_fee(storage, chain, epoch_id, &fee_config)?;
+            let paid = match EPOCH_FEES.may_load(storage, (chain.as_str(), epoch_id.to_string()))? {
+                Some(claim) => claim_active(storage, &claim)?,
+                None => false,
+            };
+            if fee.is_zero() || paid {
+                continue;
+            }
+            fees.push(WinnerInfo {
+                winner: fee_config.treasury,
+                claimable_amount: fee,
+                chain: Some(chain),
+            });
+        }
+        Ok(fees)
+    }
+
+    /// Prize of the epoch on `chain`, or of the `arb` epoch if it was not activated there, and
+    /// the protocol fee carved out of it.
+    fn epoch_prize_fee(
+        storage: &dyn Storage,
+        chain: Chain,
+        epoch_id: Uint256,
+        fee_config: &FeeConfig,
+    ) -> StdResult<(Uint256, Uint256)> {
+        let mut epoch = EPOCHS.may_load(storage, (chain.as_str(), epoch_id.to_string()))?;
+        if epoch
+            .as_ref()
+            .is_none_or(|epoch| epoch.epoch_info.is_none())
+        {
+            epoch = EPOCHS.may_load(storage, (Chain::Arb.as_str(), epoch_id.to_string()))?;
+        }
+        let prize = epoch
+            .and_then(|epoch| epoch.epoch_info)
+            .map_or(Uint256::zero(), |epoch_info| epoch_info.prize_amount);
+        Ok((prize, prize.multiply_ratio(fee_config.fee_bps, 10_000u16)))
+    }
+
+    /// Whether a claim still counts: its dispatch was not rejected by the Paloma module.
+    fn claim_active(storage: &dyn Storage, claim: &Claim) -> StdResult<bool> {
+        let dispatch = DISPATCHES.load(storage, claim.dispatch_id)?;
+        Ok(!(dispatch.status == DispatchStatus::Failed && dispatch.submitted == Some(false)))
+    }
+
+    /// Records what each dispatch of the list sends, then checks each chain's claims. A winner
+    /// sent again counts with their latest amount; `skipped` holds the positions left out.
+    fn record_claims(
+        storage: &mut dyn Storage,
+        epoch_id: Uint256,
+        winner_infos: &[WinnerInfo],
+        skipped: &[usize],
+        fees: &[WinnerInfo],
+        dispatch_ids: &[u64],
+    ) -> Result<(), ContractError> {
+        let mut chain_dispatches: BTreeMap<&str, (Chain, u64)> = BTreeMap::new();
+        for dispatch_id in dispatch_ids {
+            let chain = DISPATCHES.load(storage, *dispatch_id)?.chain;
+            chain_dispatches.insert(chain.as_str(), (chain, *dispatch_id));
+        }
+        for (index, winner_info) in winner_infos.iter().enumerate() {
+            let chain = winner_info.chain.unwrap_or(Chain::Eth);
+            if skipped.contains(&index) {
+                continue;
+            }
+            let (_, dispatch_id) = chain_dispatches[chain.as_str()];
+            EPOCH_CLAIMS.save(
+                storage,
                 (
-                    epoch_info.epoch_id.to_string(),
-                    "set_active_epoch".to_string(),
+                    (chain.as_str(), epoch_id.to_string()),
+                    winner_info.winner.as_str(),
                 ),
-                &env.block.time,
+                &Claim {
+                    amount: winner_info.claimable_amount,
+                    dispatch_id,
+                },
             )?;
         }
+        for fee in fees {
+            let chain = fee.chain.unwrap_or(Chain::Eth);
+            let (_, dispatch_id) = chain_dispatches[chain.as_str()];
+            EPOCH_FEES.save(
+                storage,
+                (chain.as_str(), epoch_id.to_string()),
+                &Claim {
+                    amount: fee.claimable_amount,
+                    dispatch_id,
+                },
+            )?;
+            let mut dispatch = DISPATCHES.load(storage, dispatch_id)?;
+            dispatch.fee = Some(fee.claimable_amount);
+            DISPATCHES.save(storage, dispatch_id, &dispatch)?;
+        }
+        for (chain, _) in chain_dispatches.into_values() {
+            check_claims(storage, chain, epoch_id)?;
+        }
+        Ok(())
+    }
 
-        if token_epoch_info_element.is_empty() {
-            Err(AllPending {})
-        } else {
-            let token_epoch_info = Token::Tuple(token_epoch_info_element);
-            Ok(Response::new()
-                .add_message(CosmosMsg::Custom(PalomaMsg {
-                    job_id: state.job_arb_id,
-                    payload: Binary(
-                        contract
-                            .function("set_active_epoch")
-                            .unwrap()
-                            .encode_input(&[token_epoch_info])
-                            .unwrap(),
-                    ),
-                    metadata: state.metadata,
-                }))
-                .add_attribute("action", "set_active_epoch"))
+    /// Fails with `PrizePoolExceeded` if the amounts sent for the epoch on a chain with a
+    /// `FeeConfig` exceed its prize less the fee.
+    fn check_claims(
+        storage: &dyn Storage,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> Result<(), ContractError> {
+        if let Some(fee_config) = FEE_CONFIGS.may_load(storage, chain.as_str())? {
+            let (prize, fee) = epoch_prize_fee(storage, chain, epoch_id, &fee_config)?;
+            let available = prize - fee;
+            let claims = EPOCH_CLAIMS
+                .prefix((chain.as_str(), epoch_id.to_string()))
+                .range(storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, claim)| claim))
+                .collect::<StdResult<Vec<Claim>>>()?;
+            let mut claimed = Uint256::zero();
+            for claim in claims {
+                if claim_active(storage, &claim)? {
+                    claimed = claimed.checked_add(claim.amount)?;
+                }
+            }
+            if claimed > available {
+                return Err(PrizePoolExceeded {
+                    chain: chain.as_str().to_string(),
+                    available: available.to_string(),
+                });
+            }
         }
+        Ok(())
     }
-}
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
-        QueryMsg::GetEthJobId {} => to_json_binary(&query::get_eth_job_id(deps)?),
-        QueryMsg::GetArbJobId {} => to_json_binary(&query::get_arb_job_id(deps)?),
+    /// Runs `set_winner_list` on the chain of each winner, one dispatch per chain, returning
+    /// each chain's response and dispatch id and the positions in `winner_infos` left out. `fees`
+    /// are appended to their chain's list. With `retry_epoch`, attempts are counted against that
+    /// epoch's payout and winners still waiting out their retry delay are skipped.
+    #[allow(clippy::type_complexity)]
+    fn dispatch_winners(
+        storage: &mut dyn Storage,
+        env: &Env,
+        state: State,
+        winner_infos: Vec<WinnerInfo>,
+        fees: Vec<WinnerInfo>,
+        retry_epoch: Option<Uint256>,
+    ) -> Result<(Vec<Response<PalomaMsg>>, Vec<u64>, Vec<usize>), ContractError> {
+        let mut groups: BTreeMap<&str, (Chain, Vec<(usize, WinnerInfo)>)> = BTreeMap::new();
+        for (index, winner_info) in winner_infos.into_iter().enumerate() {
+            let chain = winner_info.chain.unwrap_or(Chain::Eth);
+            groups
+                .entry(chain.as_str())
+                .or_insert_with(|| (chain, vec![]))
+                .1
+                .push((index, winner_info));
+        }
+        if groups.is_empty() {
+            groups.insert(Chain::Eth.as_str(), (Chain::Eth, vec![]));
+        }
+        // Nothing is sent unless every chain paid on has a job.
+        for (chain, _) in groups.values() {
+            if state.job_id(*chain).is_empty() {
+                return Err(JobNotConfigured {
+                    chain: chain.as_str().to_string(),
+                });
+            }
+        }
+
+        let policy = retry_policy(storage, RetryAction::SetWinner.as_str())?;
+        let mut responses: Vec<Response<PalomaMsg>> = vec![];
+        let mut dispatch_ids: Vec<u64> = vec![];
+        let mut all_skipped: Vec<usize> = vec![];
+        for (chain, winner_infos) in groups.into_values() {
+            let mut token_winner_info: Vec<Token> = vec![];
+            let mut retry_keys: Vec<(String, String)> = vec![];
+            let mut skipped: Vec<String> = vec![];
+            for (index, winner_info) in winner_infos {
+                if let Some(epoch_id) = retry_epoch {
+                    let key = winner_retry_key(chain, &winner_info.winner, epoch_id);
+                    if !retry_ready(storage, env, key.clone(), &policy)? {
+                        skipped.push(winner_info.winner);
+                        all_skipped.push(index);
+                        continue;
+                    }
+                    record_attempt(storage, env, key.clone())?;
+                    retry_keys.push(key);
+                }
+                token_winner_info.push(winner_info_token(&winner_info)?);
+            }
+            for fee in fees.iter().filter(|fee| fee.chain == Some(chain)) {
+                token_winner_info.push(winner_info_token(fee)?);
+            }
+            let token_winners_info: Vec<Token> = vec![Token::Array(token_winner_info)];
+
+            let payload = abi::encode_function(
+                storage,
+                chain,
+                "set_winner_list",
+                token_winners_info.as_slice(),
+            )?;
+            responses.push(dispatch(
+                storage,
+                env,
+                state.clone(),
+                chain,
+                "set_winner_list",
+                payload,
+                retry_keys,
+                skipped,
+            )?);
+            dispatch_ids.push(DISPATCH_COUNT.load(storage)?);
+        }
+        Ok((responses, dispatch_ids, all_skipped))
     }
-}
 
-pub mod query {
-    use super::*;
+    /// One response carrying every chain's submessage, with a `Vec<DispatchData>` as data.
+    fn merge_responses(
+        action: &str,
+        responses: Vec<Response<PalomaMsg>>,
+    ) -> StdResult<Response<PalomaMsg>> {
+        let mut response = Response::new().add_attribute("action", action);
+        let mut dispatched: Vec<DispatchData> = vec![];
+        for chain_response in responses {
+            if let Some(data) = chain_response.data {
+                dispatched.push(from_json(data)?);
+            }
+            response = response
+                .add_submessages(chain_response.messages)
+                .add_attributes(
+                    chain_response
+                        .attributes
+                        .into_iter()
+                        .filter(|attribute| attribute.key != "action"),
+                );
+        }
+        Ok(response.set_data(to_json_binary(&dispatched)?))
+    }
 
-    pub fn get_eth_job_id(deps: Deps) -> StdResult<GetJobIdResponse> {
+    pub fn set_arb_paloma(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
         let state = STATE.load(deps.storage)?;
-        Ok(GetJobIdResponse {
-            job_id: state.job_eth_id,
-        })
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let payload = abi::encode_function(deps.storage, Chain::Arb, "set_paloma", &[])?;
+        dispatch(
+            deps.storage,
+            &env,
+            state,
+            Chain::Arb,
+            "set_paloma",
+            payload,
+            vec![],
+            vec![],
+        )
+    }
+
+    pub fn update_arb_compass(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        new_compass: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let new_compass_address: Address = evm::parse_address(&new_compass)?;
+
+        let payload = abi::encode_function(
+            deps.storage,
+            Chain::Arb,
+            "update_compass",
+            &[Token::Address(new_compass_address)],
+        )?;
+        Ok(dispatch(
+            deps.storage,
+            &env,
+            state,
+            Chain::Arb,
+            "update_compass",
+            payload,
+            vec![],
+            vec![],
+        )?
+        .add_attribute("new_compass", evm::to_checksum(&new_compass_address)))
+    }
+
+    pub fn set_active_epoch(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        epoch_info: EpochInfo,
+        signature: Option<Binary>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if let Some(signature) = signature {
+            let payload = epoch_info_token(&epoch_info);
+            verify_attestation(
+                deps.storage,
+                deps.api,
+                &env,
+                "set_active_epoch",
+                payload,
+                &signature,
+            )?;
+        } else if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        activate_epoch(deps.storage, &env, state, Chain::Arb, epoch_info)
+    }
+
+    pub fn set_active_epoch_all(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        epoch_info: EpochInfo,
+        mut chains: Vec<Chain>,
+        signature: Option<Binary>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        chains.sort_by_key(|chain| chain.as_str());
+        chains.dedup();
+        if chains.is_empty() {
+            return Err(NoChainsSelected {});
+        }
+        if let Some(signature) = signature {
+            let payload = Token::Tuple(vec![
+                epoch_info_token(&epoch_info),
+                Token::Array(
+                    chains
+                        .iter()
+                        .map(|chain| Token::String(chain.as_str().to_string()))
+                        .collect(),
+                ),
+            ]);
+            verify_attestation(
+                deps.storage,
+                deps.api,
+                &env,
+                "set_active_epoch_all",
+                payload,
+                &signature,
+            )?;
+        } else if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+
+        let policy = retry_policy(deps.storage, RetryAction::SetActiveEpoch.as_str())?;
+        let mut response = Response::new()
+            .add_attribute("action", "set_active_epoch_all")
+            .add_attribute("epoch_id", epoch_info.epoch_id);
+        let mut dispatched: Vec<DispatchData> = vec![];
+        let mut skipped: Vec<&str> = vec![];
+        for chain in chains {
+            let key = epoch_retry_key(chain, epoch_info.epoch_id);
+            if !retry_ready(deps.storage, &env, key, &policy)? {
+                skipped.push(chain.as_str());
+                continue;
+            }
+            let activated =
+                activate_epoch(deps.storage, &env, state.clone(), chain, epoch_info.clone())?;
+            if let Some(data) = activated.data {
+                dispatched.push(from_json(data)?);
+            }
+            response = response
+                .add_submessages(activated.messages)
+                .add_attribute("dispatched", chain.as_str());
+        }
+        if dispatched.is_empty() {
+            return Err(AllPending {});
+        }
+        Ok(response
+            .add_attribute("skipped", skipped.join(","))
+            .set_data(to_json_binary(&dispatched)?))
+    }
+
+    /// Marks the epoch active and dispatches `set_active_epoch` to `chain`. Everything that can
+    /// fail is checked before the first write, so `tick` can skip an epoch that fails.
+    fn activate_epoch(
+        storage: &mut dyn Storage,
+        env: &Env,
+        state: State,
+        chain: Chain,
+        epoch_info: EpochInfo,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let policy = retry_policy(storage, RetryAction::SetActiveEpoch.as_str())?;
+        let key = epoch_retry_key(chain, epoch_info.epoch_id);
+        if !retry_ready(storage, env, key.clone(), &policy)? {
+            return Err(AllPending {});
+        }
+        if state.job_id(chain).is_empty() {
+            return Err(JobNotConfigured {
+                chain: chain.as_str().to_string(),
+            });
+        }
+        let payload = abi::encode_function(
+            storage,
+            chain,
+            "set_active_epoch",
+            &[epoch_info_token(&epoch_info)],
+        )?;
+
+        transition_epoch(
+            storage,
+            env,
+            chain,
+            epoch_info.epoch_id,
+            EpochStatus::Active,
+            Some(epoch_info.clone()),
+        )?;
+        record_attempt(storage, env, key.clone())?;
+        let response = dispatch(
+            storage,
+            env,
+            state,
+            chain,
+            "set_active_epoch",
+            payload,
+            vec![key],
+            vec![],
+        )?;
+        let dispatch_id = DISPATCH_COUNT.load(storage)?;
+        set_dispatch_epoch(storage, dispatch_id, epoch_info.epoch_id)?;
+        Ok(response)
+    }
+
+    pub fn tick(deps: DepsMut, env: Env) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        let now = Uint256::from(env.block.time.seconds());
+        let mut response = Response::new().add_attribute("action", "tick");
+        let mut dispatched: Vec<DispatchData> = vec![];
+        for chain in [Chain::Eth, Chain::Arb] {
+            // The queue is ordered by start, so it is due up to the first epoch still to come.
+            let due = EPOCH_QUEUE
+                .prefix(chain.as_str())
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, epoch_info)| epoch_info))
+                .take_while(|item| {
+                    item.as_ref()
+                        .map_or(true, |epoch_info| epoch_info.competition_start <= now)
+                })
+                .collect::<StdResult<Vec<EpochInfo>>>()?;
+            for epoch_info in due {
+                let epoch_id = epoch_info.epoch_id;
+                // An epoch that ended while queued is left for the owner to cancel.
+                if epoch_info.competition_end <= now {
+                    response = response
+                        .add_attribute("expired", format!("{}:{}", chain.as_str(), epoch_id));
+                    continue;
+                }
+                // One epoch that can't be activated does not hold back the others.
+                let activated =
+                    match activate_epoch(deps.storage, &env, state.clone(), chain, epoch_info) {
+                        Ok(activated) => activated,
+                        Err(err) => {
+                            response = response.add_attribute(
+                                "failed",
+                                format!("{}:{}:{}", chain.as_str(), epoch_id, err),
+                            );
+                            continue;
+                        }
+                    };
+                if let Some(data) = activated.data {
+                    dispatched.push(from_json(data)?);
+                }
+                response = response
+                    .add_submessages(activated.messages)
+                    .add_attribute("activated", format!("{}:{}", chain.as_str(), epoch_id));
+            }
+        }
+        Ok(response.set_data(to_json_binary(&dispatched)?))
+    }
+
+    pub fn add_attestor(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        pubkey: Binary,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        // Compressed or uncompressed SEC1 encoding.
+        if pubkey.len() != 33 && pubkey.len() != 65 {
+            return Err(InvalidPubkey {});
+        }
+        // An attestor can relay without the owner, so adding one needs the approvers too.
+        if approval_enabled(deps.storage)? {
+            let action = ProposalAction::AddAttestor { pubkey };
+            return create_proposal(deps, &env, Uint256::zero(), vec![], action);
+        }
+        save_attestor(deps.storage, pubkey)
+    }
+
+    fn save_attestor(
+        storage: &mut dyn Storage,
+        pubkey: Binary,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        ATTESTORS.save(storage, pubkey.as_slice(), &Empty {})?;
+        Ok(Response::new()
+            .add_attribute("action", "add_attestor")
+            .add_attribute("pubkey", pubkey.to_base64()))
+    }
+
+    pub fn remove_attestor(
+        deps: DepsMut,
+        info: MessageInfo,
+        pubkey: Binary,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        ATTESTORS.remove(deps.storage, pubkey.as_slice());
+        Ok(Response::new()
+            .add_attribute("action", "remove_attestor")
+            .add_attribute("pubkey", pubkey.to_base64()))
+    }
+
+    pub fn call_target(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        function: String,
+        args: Vec<AbiValue>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        if approval_enabled(deps.storage)? {
+            let (signature, _) = target_call(deps.storage, chain, &function, &args)?;
+            let action = ProposalAction::CallTarget {
+                chain,
+                function: signature,
+                args,
+            };
+            return create_proposal(deps, &env, Uint256::zero(), vec![], action);
+        }
+        send_target_call(deps.storage, &env, state, chain, &function, &args)
+    }
+
+    /// Canonical signature and calldata of an allowlisted, unmanaged function.
+    fn target_call(
+        storage: &dyn Storage,
+        chain: Chain,
+        function: &str,
+        args: &[AbiValue],
+    ) -> Result<(String, Vec<u8>), ContractError> {
+        let signature = abi::canonical_signature(function)?;
+        if !CALL_ALLOWLIST.has(storage, (chain.as_str(), &signature)) {
+            return Err(CallNotAllowed {
+                function: signature,
+            });
+        }
+        let function = abi::parse_signature(&signature)?;
+        if abi::is_managed(storage, chain, &function)? {
+            return Err(ManagedFunction {
+                function: signature,
+            });
+        }
+        let payload = abi::encode_call(&function, args)?;
+        Ok((signature, payload))
+    }
+
+    fn send_target_call(
+        storage: &mut dyn Storage,
+        env: &Env,
+        state: State,
+        chain: Chain,
+        function: &str,
+        args: &[AbiValue],
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let (signature, payload) = target_call(storage, chain, function, args)?;
+        Ok(dispatch(
+            storage,
+            env,
+            state,
+            chain,
+            "call_target",
+            payload,
+            vec![],
+            vec![],
+        )?
+        .add_attribute("function", signature))
+    }
+
+    pub fn allow_call(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+        function: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let signature = abi::canonical_signature(&function)?;
+        if abi::is_managed(deps.storage, chain, &abi::parse_signature(&signature)?)? {
+            return Err(ManagedFunction {
+                function: signature,
+            });
+        }
+        CALL_ALLOWLIST.save(deps.storage, (chain.as_str(), &signature), &Empty {})?;
+        Ok(Response::new()
+            .add_attribute("action", "allow_call")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("function", signature))
+    }
+
+    pub fn disallow_call(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+        function: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let signature = abi::canonical_signature(&function)?;
+        CALL_ALLOWLIST.remove(deps.storage, (chain.as_str(), &signature));
+        Ok(Response::new()
+            .add_attribute("action", "disallow_call")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("function", signature))
+    }
+
+    pub fn set_target_abi(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+        abi: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        abi::parse_abi(&abi)?;
+        TARGET_ABIS.save(deps.storage, chain.as_str(), &abi)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_target_abi")
+            .add_attribute("chain", chain.as_str()))
+    }
+
+    pub fn clear_target_abi(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        TARGET_ABIS.remove(deps.storage, chain.as_str());
+        Ok(Response::new()
+            .add_attribute("action", "clear_target_abi")
+            .add_attribute("chain", chain.as_str()))
+    }
+
+    pub fn grant_role(
+        deps: DepsMut,
+        info: MessageInfo,
+        role: Role,
+        address: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let address = deps.api.addr_validate(&address)?;
+        ROLES.save(deps.storage, (role.as_str(), &address), &Empty {})?;
+        Ok(Response::new()
+            .add_attribute("action", "grant_role")
+            .add_attribute("role", role.as_str())
+            .add_attribute("address", address))
+    }
+
+    pub fn revoke_role(
+        deps: DepsMut,
+        info: MessageInfo,
+        role: Role,
+        address: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let address = deps.api.addr_validate(&address)?;
+        ROLES.remove(deps.storage, (role.as_str(), &address));
+        Ok(Response::new()
+            .add_attribute("action", "revoke_role")
+            .add_attribute("role", role.as_str())
+            .add_attribute("address", address))
+    }
+
+    pub fn report_execution(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        dispatch_id: u64,
+        evm_tx_hash: String,
+        success: bool,
+        error: Option<String>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        if !ROLES.has(deps.storage, (Role::Relayer.as_str(), &info.sender)) {
+            return Err(Unauthorized {});
+        }
+        let mut dispatch = DISPATCHES.load(deps.storage, dispatch_id)?;
+        if dispatch.status != DispatchStatus::Pending {
+            return Err(DispatchNotPending {});
+        }
+        dispatch.status = if success {
+            DispatchStatus::Confirmed
+        } else {
+            DispatchStatus::Failed
+        };
+        dispatch.evm_tx_hash = Some(evm_tx_hash.clone());
+        dispatch.error = error;
+        dispatch.reported_at = Some(env.block.time);
+        for key in dispatch.retry_keys.iter() {
+            // Keys claimed by a later dispatch since are left to that dispatch.
+            if let Some(mut retry) = WITHDRAW_TIMESTAMP
+                .may_load(deps.storage, key.clone())?
+                .filter(|retry| retry.timestamp == dispatch.last_sent_at)
+            {
+                if success {
+                    // The retry delay still guards against resending, but attempts start over.
+                    retry.attempts = 0;
+                } else {
+                    retry.reopened = true;
+                }
+                WITHDRAW_TIMESTAMP.save(deps.storage, key.clone(), &retry)?;
+            }
+        }
+        if let Some(fee) = dispatch.fee.filter(|_| success) {
+            FEE_TOTALS.update(
+                deps.storage,
+                dispatch.chain.as_str(),
+                |fee_total| -> Result<Uint256, ContractError> {
+                    Ok(fee_total.unwrap_or_default().checked_add(fee)?)
+                },
+            )?;
+        }
+        if let Some(epoch_id) = dispatch.epoch_id.filter(|_| success)
+            && let Some(chain) = payout_epoch_chain(deps.storage, epoch_id, dispatch_id)?
+            // An epoch already paid by an earlier resend is left as it is.
+            && epoch_status(deps.storage, &env, chain, epoch_id)? == Some(EpochStatus::Settled)
+            // Other dispatches of the epoch may still be waiting for their payout.
+            && !payouts_pending(deps.storage, chain, epoch_id, dispatch_id)?
+        {
+            transition_epoch(deps.storage, &env, chain, epoch_id, EpochStatus::Paid, None)?;
+        }
+        DISPATCHES.save(deps.storage, dispatch_id, &dispatch)?;
+        Ok(Response::new()
+            .add_attribute("action", "report_execution")
+            .add_attribute("dispatch_id", dispatch_id.to_string())
+            .add_attribute("evm_tx_hash", evm_tx_hash)
+            .add_attribute("success", success.to_string()))
+    }
+
+    pub fn resend(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        dispatch_id: u64,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender
+            && !ROLES.has(deps.storage, (Role::Operator.as_str(), &info.sender))
+        {
+            return Err(Unauthorized {});
+        }
+        let mut dispatch = DISPATCHES.load(deps.storage, dispatch_id)?;
+        // Retry keys of chains other than the action's first one carry a `:chain` suffix.
+        let action = dispatch
+            .retry_keys
+            .first()
+            .map_or(dispatch.action.as_str(), |key| {
+                key.1.split(':').next().unwrap_or_default()
+            });
+        let policy = retry_policy(deps.storage, action)?;
+        let attempts = dispatch.resend_count + 1;
+        if attempts >= policy.max_attempts {
+            return Err(RetryExhausted {});
+        }
+        match dispatch.status {
+            DispatchStatus::Confirmed => return Err(DispatchConfirmed {}),
+            DispatchStatus::Pending => {
+                if !dispatch
+                    .last_sent_at
+                    .plus_seconds(policy.delay(attempts))
+                    .lt(&env.block.time)
+                {
+                    return Err(AllPending {});
+                }
+            }
+            DispatchStatus::Failed => {}
+        }
+        // Resends follow the epoch lifecycle: start and amend only while it runs, and never pay
+        // out a cancelled epoch.
+        if let Some(epoch_id) = dispatch.epoch_id {
+            let chain = match dispatch.action.as_str() {
+                "set_winner_list" => {
+                    payout_epoch_chain(deps.storage, epoch_id, dispatch_id)?.unwrap_or(Chain::Arb)
+                }
+                _ => dispatch.chain,
+            };
+            let status = EPOCHS
+                .may_load(deps.storage, (chain.as_str(), epoch_id.to_string()))?
+                .map(|epoch| epoch.status_at(env.block.time));
+            match dispatch.action.as_str() {
+                "set_active_epoch" | "amend_epoch" if status != Some(EpochStatus::Active) => {
+                    return Err(EpochNotActive {});
+                }
+                "set_winner_list" if status == Some(EpochStatus::Cancelled) => {
+                    return Err(EpochCancelled {});
+                }
+                _ => {}
+            }
+            // A fee taken over by a later list after this one was rejected is not sent twice.
+            if dispatch.fee.is_some()
+                && EPOCH_FEES
+                    .may_load(
+                        deps.storage,
+                        (dispatch.chain.as_str(), epoch_id.to_string()),
+                    )?
+                    .is_some_and(|claim| claim.dispatch_id != dispatch_id)
+            {
+                return Err(DispatchSuperseded {});
+            }
+        }
+        for key in dispatch.retry_keys.iter() {
+            if let Some(retry) = WITHDRAW_TIMESTAMP.may_load(deps.storage, key.clone())? {
+                // A key claimed by a later dispatch has already been sent again.
+                if retry.timestamp != dispatch.last_sent_at {
+                    return Err(DispatchSuperseded {});
+                }
+                if retry.status(&policy, env.block.time) == RetryStatus::Failed {
+                    return Err(RetryExhausted {});
+                }
+            }
+            record_attempt(deps.storage, &env, key.clone())?;
+        }
+        dispatch.status = DispatchStatus::Pending;
+        dispatch.last_sent_at = env.block.time;
+        dispatch.resend_count += 1;
+        dispatch.submitted = None;
+        dispatch.submit_error = None;
+        dispatch.evm_tx_hash = None;
+        dispatch.error = None;
+        dispatch.reported_at = None;
+        DISPATCHES.save(deps.storage, dispatch_id, &dispatch)?;
+        // A rejected list sent again counts against the prize again.
+        if dispatch.action == "set_winner_list"
+            && let Some(epoch_id) = dispatch.epoch_id
+        {
+            check_claims(deps.storage, dispatch.chain, epoch_id)?;
+        }
+        let data = dispatch_data(
+            dispatch_id,
+            dispatch.chain,
+            &dispatch.action,
+            &dispatch.job_id,
+            &dispatch.payload,
+            &dispatch.retry_keys,
+            vec![],
+        )?;
+        Ok(Response::new()
+            .set_data(data)
+            .add_submessage(SubMsg::reply_always(
+                CosmosMsg::Custom(PalomaMsg::execute_job(
+                    &state.paloma_msg_version,
+                    dispatch.job_id,
+                    dispatch.payload,
+                    dispatch.metadata,
+                )),
+                dispatch_id,
+            ))
+            .add_attribute("action", "resend")
+            .add_attribute("chain", dispatch.chain.as_str())
+            .add_attribute("dispatch_id", dispatch_id.to_string())
+            .add_attribute("resend_count", dispatch.resend_count.to_string()))
+    }
+
+    fn set_dispatch_epoch(
+        storage: &mut dyn Storage,
+        dispatch_id: u64,
+        epoch_id: Uint256,
+    ) -> StdResult<()> {
+        let mut dispatch = DISPATCHES.load(storage, dispatch_id)?;
+        dispatch.epoch_id = Some(epoch_id);
+        DISPATCHES.save(storage, dispatch_id, &dispatch)
+    }
+
+    /// Emits `payload` to the chain's job and records it as a pending dispatch.
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch(
+        storage: &mut dyn Storage,
+        env: &Env,
+        state: State,
+        chain: Chain,
+        action: &str,
+        payload: Vec<u8>,
+        retry_keys: Vec<(String, String)>,
+        skipped: Vec<String>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let job_id = state.job_id(chain).to_string();
+        if job_id.is_empty() {
+            return Err(JobNotConfigured {
+                chain: chain.as_str().to_string(),
+            });
+        }
+        let dispatch_id = DISPATCH_COUNT.may_load(storage)?.unwrap_or_default() + 1;
+        let payload = Binary(payload);
+        DISPATCHES.save(
+            storage,
+            dispatch_id,
+            &Dispatch {
+                chain,
+                action: action.to_string(),
+                job_id: job_id.clone(),
+                payload: payload.clone(),
+                metadata: state.metadata.clone(),
+                status: DispatchStatus::Pending,
+                created_at: env.block.time,
+                last_sent_at: env.block.time,
+                resend_count: 0,
+                retry_keys: retry_keys.clone(),
+                epoch_id: None,
+                submitted: None,
+                submit_error: None,
+                fee: None,
+                evm_tx_hash: None,
+                error: None,
+                reported_at: None,
+            },
+        )?;
+        DISPATCH_COUNT.save(storage, &dispatch_id)?;
+        let data = dispatch_data(
+            dispatch_id,
+            chain,
+            action,
+            &job_id,
+            &payload,
+            &retry_keys,
+            skipped,
+        )?;
+        let msg =
+            PalomaMsg::execute_job(&state.paloma_msg_version, job_id, payload, state.metadata);
+        Ok(Response::new()
+            .set_data(data)
+            .add_submessage(SubMsg::reply_always(CosmosMsg::Custom(msg), dispatch_id))
+            .add_attribute("action", action)
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("dispatch_id", dispatch_id.to_string()))
+    }
+
+    fn dispatch_data(
+        dispatch_id: u64,
+        chain: Chain,
+        action: &str,
+        job_id: &str,
+        payload: &Binary,
+        retry_keys: &[(String, String)],
+        skipped: Vec<String>,
+    ) -> StdResult<Binary> {
+        to_json_binary(&DispatchData {
+            dispatch_id,
+            chain,
+            action: action.to_string(),
+            job_id: job_id.to_string(),
+            payload_hash: Binary(Sha256::digest(payload.as_slice()).to_vec()),
+            // Winner keys carry the payout epoch after a colon.
+            dispatched: retry_keys
+                .iter()
+                .map(|key| key.0.split(':').next().unwrap_or_default().to_string())
+                .collect(),
+            skipped,
+        })
+    }
+
+    /// Hash an attestor signs for `action`: SHA-256 of
+    /// `abi.encode(string contract_address, string action, payload)`.
+    pub fn attestation_hash(env: &Env, action: &str, payload: Token) -> Vec<u8> {
+        Sha256::digest(ethabi::encode(&[
+            Token::String(env.contract.address.to_string()),
+            Token::String(action.to_string()),
+            payload,
+        ]))
+        .to_vec()
+    }
+
+    /// Succeeds if `signature` over the attestation hash verifies against any registered attestor,
+    /// and marks the hash used so the same attested payload cannot be relayed again. That holds
+    /// even when part of the call was skipped by the retry gate; sending it again takes a new
+    /// signature over a changed payload, or a `Resend` of its dispatch.
+    fn verify_attestation(
+        storage: &mut dyn Storage,
+        api: &dyn Api,
+        env: &Env,
+        action: &str,
+        payload: Token,
+        signature: &Binary,
+    ) -> Result<(), ContractError> {
+        let hash = attestation_hash(env, action, payload);
+        if USED_ATTESTATIONS.has(storage, &hash) {
+            return Err(AttestationUsed {});
+        }
+        let attestors = ATTESTORS
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for pubkey in attestors {
+            if api.secp256k1_verify(&hash, signature, &pubkey)? {
+                USED_ATTESTATIONS.save(storage, &hash, &Empty {})?;
+                return Ok(());
+            }
+        }
+        Err(InvalidAttestation {})
+    }
+
+    pub fn update_retry_policy(
+        deps: DepsMut,
+        info: MessageInfo,
+        action: Option<RetryAction>,
+        retry_policy: RetryPolicy,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let mut state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        validate_retry_policy(&retry_policy)?;
+        match action {
+            Some(action) => RETRY_POLICIES.save(deps.storage, action.as_str(), &retry_policy)?,
+            None => {
+                state.retry_policy = retry_policy;
+                STATE.save(deps.storage, &state)?;
+            }
+        }
+        Ok(Response::new()
+            .add_attribute("action", "update_retry_policy")
+            .add_attribute(
+                "retry_action",
+                action.map_or("default", |action| action.as_str()),
+            ))
+    }
+
+    pub fn reset_retry(
+        deps: DepsMut,
+        info: MessageInfo,
+        action: RetryAction,
+        key: String,
+        chain: Option<Chain>,
+        epoch_id: Option<Uint256>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let key = retry_key(action, &key, chain, epoch_id)?;
+        WITHDRAW_TIMESTAMP.remove(deps.storage, key.clone());
+        Ok(Response::new()
+            .add_attribute("action", "reset_retry")
+            .add_attribute("retry_action", key.1)
+            .add_attribute("key", key.0))
+    }
+
+    pub fn set_rollback_on_submit_failure(
+        deps: DepsMut,
+        info: MessageInfo,
+        enabled: bool,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let mut state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        state.rollback_on_submit_failure = enabled;
+        STATE.save(deps.storage, &state)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_rollback_on_submit_failure")
+            .add_attribute("enabled", enabled.to_string()))
+    }
+
+    pub fn set_paloma_msg_version(
+        deps: DepsMut,
+        info: MessageInfo,
+        version: PalomaMsgVersion,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let mut state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        state.paloma_msg_version = version;
+        STATE.save(deps.storage, &state)?;
+        Ok(Response::new().add_attribute("action", "set_paloma_msg_version"))
+    }
+
+    pub fn set_job_id(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+        job_id: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let mut state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        match chain {
+            Chain::Eth => state.job_eth_id = job_id.clone(),
+            Chain::Arb => state.job_arb_id = job_id.clone(),
+        }
+        STATE.save(deps.storage, &state)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_job_id")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("job_id", job_id))
+    }
+
+    pub fn set_chain_config(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+        chain_reference_id: String,
+        competition_contract: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let config = ChainConfig {
+            chain_reference_id,
+            competition_contract: evm::normalize_address(&competition_contract)?,
+        };
+        CHAIN_CONFIGS.save(deps.storage, chain.as_str(), &config)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_chain_config")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("chain_reference_id", config.chain_reference_id)
+            .add_attribute("competition_contract", config.competition_contract))
+    }
+
+    pub fn set_fee_config(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        fee_bps: u16,
+        treasury: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        if fee_bps > 10_000 {
+            return Err(InvalidFee {});
+        }
+        let treasury = evm::normalize_address(&treasury)?;
+        // Fees are paid out of winner lists, so changing them needs the approvers too.
+        if approval_enabled(deps.storage)? {
+            let action = ProposalAction::SetFeeConfig {
+                chain,
+                fee_bps,
+                treasury,
+            };
+            return create_proposal(deps, &env, Uint256::zero(), vec![], action);
+        }
+        save_fee_config(deps.storage, chain, fee_bps, treasury)
+    }
+
+    fn save_fee_config(
+        storage: &mut dyn Storage,
+        chain: Chain,
+        fee_bps: u16,
+        treasury: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let config = FeeConfig { fee_bps, treasury };
+        FEE_CONFIGS.save(storage, chain.as_str(), &config)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_fee_config")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("fee_bps", fee_bps.to_string())
+            .add_attribute("treasury", config.treasury))
+    }
+
+    pub fn fund_prize_pool(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.paloma_msg_version == PalomaMsgVersion::V1 {
+            return Err(SkywayNotSupported {});
+        }
+        check_fundable(deps.storage, &env, chain, epoch_id)?;
+        let config = CHAIN_CONFIGS
+            .may_load(deps.storage, chain.as_str())?
+            .ok_or(ChainNotConfigured {
+                chain: chain.as_str().to_string(),
+            })?;
+        let [coin] = info.funds.as_slice() else {
+            return Err(InvalidFunds {});
+        };
+        if coin.amount.is_zero() {
+            return Err(InvalidFunds {});
+        }
+        PRIZE_FUNDING.update(
+            deps.storage,
+            (chain.as_str(), epoch_id.to_string(), &coin.denom),
+            |funded| -> Result<Uint128, ContractError> {
+                Ok(funded.unwrap_or_default().checked_add(coin.amount)?)
+            },
+        )?;
+        Ok(Response::new()
+            .add_message(CosmosMsg::Custom(PalomaMsg::V2(PalomaMsgV2::SkywayMsg {
+                send_tx: Some(SendTx {
+                    remote_chain_destination_address: config.competition_contract,
+                    amount: coin.to_string(),
+                    chain_reference_id: config.chain_reference_id,
+                }),
+                cancel_tx: None,
+            })))
+            .add_attribute("action", "fund_prize_pool")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("epoch_id", epoch_id)
+            .add_attribute("funder", info.sender)
+            .add_attribute("amount", coin.to_string()))
+    }
+
+    pub fn receive(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Cw20ReceiveMsg,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        match from_json(&msg.msg)? {
+            Cw20HookMsg::FundEpoch { epoch_id, chain } => {
+                let chain = chain.unwrap_or(Chain::Arb);
+                let key = epoch_id.to_string();
+                check_fundable(deps.storage, &env, chain, epoch_id)?;
+                if msg.amount.is_zero() {
+                    return Err(InvalidFunds {});
+                }
+                // The CW20 contract calling the hook is the token.
+                let token = info.sender;
+                let sponsor = deps.api.addr_validate(&msg.sender)?;
+                SPONSORSHIPS.update(
+                    deps.storage,
+                    ((chain.as_str(), key), &token, &sponsor),
+                    |amount| -> Result<Uint128, ContractError> {
+                        Ok(amount.unwrap_or_default().checked_add(msg.amount)?)
+                    },
+                )?;
+                Ok(Response::new()
+                    .add_attribute("action", "fund_epoch")
+                    .add_attribute("chain", chain.as_str())
+                    .add_attribute("epoch_id", epoch_id)
+                    .add_attribute("token", token)
+                    .add_attribute("sponsor", sponsor)
+                    .add_attribute("amount", msg.amount))
+            }
+        }
+    }
+
+    pub fn schedule_epoch(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_info: EpochInfo,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let epoch_id = epoch_info.epoch_id;
+        queue_epoch(deps.storage, &env, chain, epoch_info)?;
+        Ok(Response::new()
+            .add_attribute("action", "schedule_epoch")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("epoch_id", epoch_id))
+    }
+
+    pub fn set_epoch_template(
+        deps: DepsMut,
+        info: MessageInfo,
+        chain: Chain,
+        template: EpochTemplate,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        if template.duration == 0 {
+            return Err(InvalidEpochTemplate {});
+        }
+        EPOCH_TEMPLATES.save(deps.storage, chain.as_str(), &template)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_epoch_template")
+            .add_attribute("chain", chain.as_str()))
+    }
+
+    pub fn start_next_epoch(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let template = EPOCH_TEMPLATES
+            .may_load(deps.storage, chain.as_str())?
+            .ok_or_else(|| EpochTemplateNotConfigured {
+                chain: chain.as_str().to_string(),
+            })?;
+        let previous = match EPOCH_QUEUE
+            .prefix(chain.as_str())
+            .range(deps.storage, None, None, Order::Descending)
+            .next()
+            .transpose()?
+        {
+            Some((_, epoch_info)) => Some(epoch_info),
+            None => LATEST_EPOCHS.may_load(deps.storage, chain.as_str())?,
+        };
+        let mut epoch_id = previous.as_ref().map_or(Uint256::one(), |previous| {
+            previous.epoch_id + Uint256::one()
+        });
+        // Ids of epochs cancelled while queued are not reused.
+        while EPOCHS.has(deps.storage, (chain.as_str(), epoch_id.to_string())) {
+            epoch_id += Uint256::one();
+        }
+
+        let now = Uint256::from(env.block.time.seconds());
+        let duration = Uint256::from(template.duration);
+        let period = duration + Uint256::from(template.gap);
+        let mut start = Uint256::from(template.start_anchor);
+        if let Some(previous) = &previous {
+            start = start.max(previous.competition_end + Uint256::from(template.gap));
+        }
+        // Skip the slots that have already ended.
+        if start + duration <= now {
+            start += (now - start - duration) / period * period + period;
+        }
+        let epoch_info = EpochInfo {
+            epoch_id,
+            competition_start: start,
+            competition_end: start + duration,
+            entry_cnt: Uint256::zero(),
+            prize_amount: template.prize_amount,
+        };
+        if start <= now {
+            return Ok(
+                activate_epoch(deps.storage, &env, state, chain, epoch_info)?
+                    .add_attribute("epoch_id", epoch_id),
+            );
+        }
+        queue_epoch(deps.storage, &env, chain, epoch_info)?;
+        Ok(Response::new()
+            .add_attribute("action", "start_next_epoch")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("epoch_id", epoch_id)
+            .add_attribute("competition_start", start))
+    }
+
+    /// Schedules an epoch, keeping each chain's epochs in order and without overlaps.
+    fn queue_epoch(
+        storage: &mut dyn Storage,
+        env: &Env,
+        chain: Chain,
+        epoch_info: EpochInfo,
+    ) -> Result<(), ContractError> {
+        if epoch_info.competition_start <= Uint256::from(env.block.time.seconds())
+            || epoch_info.competition_start >= epoch_info.competition_end
+        {
+            return Err(InvalidEpochWindow {});
+        }
+        let epoch_id = epoch_info.epoch_id;
+        let id = epoch_id.to_be_bytes();
+        let latest = LATEST_EPOCHS.may_load(storage, chain.as_str())?;
+        if latest
+            .as_ref()
+            .is_some_and(|latest| latest.epoch_id >= epoch_id)
+        {
+            return Err(EpochOutOfOrder {});
+        }
+        // Neighbours in id order must also be neighbours in time.
+        let queue = EPOCH_QUEUE.prefix(chain.as_str());
+        let previous = queue
+            .range(
+                storage,
+                None,
+                Some(Bound::exclusive(id.as_slice())),
+                Order::Descending,
+            )
+            .next()
+            .transpose()?
+            .map(|(_, epoch_info)| epoch_info)
+            .or(latest);
+        if let Some(previous) = previous
+            && previous.competition_end > epoch_info.competition_start
+        {
+            return Err(EpochOverlap {
+                epoch_id: previous.epoch_id.to_string(),
+            });
+        }
+        let next = queue
+            .range(
+                storage,
+                Some(Bound::exclusive(id.as_slice())),
+                None,
+                Order::Ascending,
+            )
+            .next()
+            .transpose()?;
+        if let Some((_, next)) = next
+            && epoch_info.competition_end > next.competition_start
+        {
+            return Err(EpochOverlap {
+                epoch_id: next.epoch_id.to_string(),
+            });
+        }
+        transition_epoch(
+            storage,
+            env,
+            chain,
+            epoch_id,
+            EpochStatus::Scheduled,
+            Some(epoch_info.clone()),
+        )?;
+        EPOCH_QUEUE.save(storage, (chain.as_str(), id.as_slice()), &epoch_info)?;
+        Ok(())
+    }
+
+    pub fn close_epoch(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        transition_epoch(
+            deps.storage,
+            &env,
+            chain,
+            epoch_id,
+            EpochStatus::Closed,
+            None,
+        )?;
+        Ok(Response::new()
+            .add_attribute("action", "close_epoch")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("epoch_id", epoch_id))
+    }
+
+    pub fn amend_epoch(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_id: Uint256,
+        prize_amount: Option<Uint256>,
+        competition_end: Option<Uint256>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let key = (chain.as_str(), epoch_id.to_string());
+        let mut epoch = EPOCHS
+            .may_load(deps.storage, key.clone())?
+            .filter(|epoch| epoch.status_at(env.block.time) == EpochStatus::Active)
+            .ok_or(EpochNotActive {})?;
+        let mut epoch_info = epoch.epoch_info.clone().ok_or(EpochNotActive {})?;
+        if prize_amount.is_none() && competition_end.is_none() {
+            return Err(InvalidAmendment {});
+        }
+        let previous = epoch_info.clone();
+        if let Some(prize_amount) = prize_amount {
+            if prize_amount <= epoch_info.prize_amount {
+                return Err(InvalidAmendment {});
+            }
+            epoch_info.prize_amount = prize_amount;
+        }
+        if let Some(competition_end) = competition_end {
+            if competition_end <= epoch_info.competition_end {
+                return Err(InvalidAmendment {});
+            }
+            // The extension must not run into the next queued epoch.
+            let next = EPOCH_QUEUE
+                .prefix(chain.as_str())
+                .range(
+                    deps.storage,
+                    Some(Bound::exclusive(epoch_id.to_be_bytes().as_slice())),
+                    None,
+                    Order::Ascending,
+                )
+                .next()
+                .transpose()?;
+            if let Some((_, next)) = next
+                && competition_end > next.competition_start
+            {
+                return Err(EpochOverlap {
+                    epoch_id: next.epoch_id.to_string(),
+                });
+            }
+            epoch_info.competition_end = competition_end;
+        }
+
+        let payload = abi::encode_function(
+            deps.storage,
+            chain,
+            "amend_epoch",
+            &[
+                epoch_info.epoch_id,
+                epoch_info.prize_amount,
+                epoch_info.competition_end,
+            ]
+            .iter()
+            .map(|value| Token::Uint(Uint::from_big_endian(&value.to_be_bytes())))
+            .collect::<Vec<Token>>(),
+        )?;
+        let response = dispatch(
+            deps.storage,
+            &env,
+            state,
+            chain,
+            "amend_epoch",
+            payload,
+            vec![],
+            vec![],
+        )?;
+        let dispatch_id = DISPATCH_COUNT.load(deps.storage)?;
+        set_dispatch_epoch(deps.storage, dispatch_id, epoch_id)?;
+        epoch.amendments.push(EpochAmendment {
+            previous_prize_amount: previous.prize_amount,
+            prize_amount: epoch_info.prize_amount,
+            previous_competition_end: previous.competition_end,
+            competition_end: epoch_info.competition_end,
+            amended_at: env.block.time,
+            dispatch_id,
+        });
+        epoch.epoch_info = Some(epoch_info.clone());
+        EPOCHS.save(deps.storage, key, &epoch)?;
+        if LATEST_EPOCHS
+            .may_load(deps.storage, chain.as_str())?
+            .is_some_and(|latest| latest.epoch_id == epoch_id)
+        {
+            LATEST_EPOCHS.save(deps.storage, chain.as_str(), &epoch_info)?;
+        }
+        Ok(response
+            .add_attribute("epoch_id", epoch_id)
+            .add_attribute("prize_amount", epoch_info.prize_amount)
+            .add_attribute("competition_end", epoch_info.competition_end))
+    }
+
+    pub fn cancel_epoch(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_id: Uint256,
+        reason: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let from = epoch_status(deps.storage, &env, chain, epoch_id)?;
+        transition_epoch(
+            deps.storage,
+            &env,
+            chain,
+            epoch_id,
+            EpochStatus::Cancelled,
+            None,
+        )?;
+        let key = (chain.as_str(), epoch_id.to_string());
+        let mut epoch = EPOCHS.load(deps.storage, key.clone())?;
+        epoch.cancel_reason = Some(reason.clone());
+        EPOCHS.save(deps.storage, key, &epoch)?;
+
+        // An epoch that was only scheduled, or never recorded, did not reach the competition
+        // contract.
+        if matches!(from, None | Some(EpochStatus::Scheduled)) {
+            return Ok(Response::new()
+                .add_attribute("action", "cancel_epoch")
+                .add_attribute("chain", chain.as_str())
+                .add_attribute("epoch_id", epoch_id)
+                .add_attribute("reason", reason));
+        }
+        let payload = abi::encode_function(
+            deps.storage,
+            chain,
+            "cancel_epoch",
+            &[Token::Uint(Uint::from_big_endian(&epoch_id.to_be_bytes()))],
+        )?;
+        Ok(dispatch(
+            deps.storage,
+            &env,
+            state,
+            chain,
+            "cancel_epoch",
+            payload,
+            vec![],
+            vec![],
+        )?
+        .add_attribute("epoch_id", epoch_id)
+        .add_attribute("reason", reason))
+    }
+
+    pub fn refund_sponsorship(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_id: Uint256,
+        token: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        if epoch_status(deps.storage, &env, chain, epoch_id)? != Some(EpochStatus::Cancelled) {
+            return Err(EpochNotCancelled {});
+        }
+        let key = epoch_id.to_string();
+        let token = deps.api.addr_validate(&token)?;
+        let sponsorship = ((chain.as_str(), key), &token, &info.sender);
+        let amount = SPONSORSHIPS
+            .may_load(deps.storage, sponsorship.clone())?
+            .ok_or(NothingToRefund {})?;
+        SPONSORSHIPS.remove(deps.storage, sponsorship);
+        Ok(Response::new()
+            .add_message(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: info.sender.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            })
+            .add_attribute("action", "refund_sponsorship")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("epoch_id", epoch_id)
+            .add_attribute("token", token)
+            .add_attribute("sponsor", info.sender)
+            .add_attribute("amount", amount))
+    }
+
+    /// Sends every contribution of `token` to a paid epoch to `recipient`, since sponsors are
+    /// only refunded when an epoch is cancelled.
+    pub fn sweep_sponsorships(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        chain: Chain,
+        epoch_id: Uint256,
+        token: String,
+        recipient: String,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        if epoch_status(deps.storage, &env, chain, epoch_id)? != Some(EpochStatus::Paid) {
+            return Err(EpochNotPaid {});
+        }
+        let key = epoch_id.to_string();
+        let token = deps.api.addr_validate(&token)?;
+        let recipient = deps.api.addr_validate(&recipient)?;
+        let sponsorships = SPONSORSHIPS
+            .prefix(((chain.as_str(), key.clone()), &token))
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(Addr, Uint128)>>>()?;
+        let mut amount = Uint128::zero();
+        for (sponsor, sponsored) in sponsorships {
+            amount = amount.checked_add(sponsored)?;
+            SPONSORSHIPS.remove(
+                deps.storage,
+                ((chain.as_str(), key.clone()), &token, &sponsor),
+            );
+        }
+        if amount.is_zero() {
+            return Err(NothingToRefund {});
+        }
+        Ok(Response::new()
+            .add_message(WasmMsg::Execute {
+                contract_addr: token.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            })
+            .add_attribute("action", "sweep_sponsorships")
+            .add_attribute("chain", chain.as_str())
+            .add_attribute("epoch_id", epoch_id)
+            .add_attribute("token", token)
+            .add_attribute("recipient", recipient)
+            .add_attribute("amount", amount))
+    }
+
+    pub fn set_season(
+        deps: DepsMut,
+        info: MessageInfo,
+        season_id: u64,
+        season_info: SeasonInfo,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        if season_info.start >= season_info.end {
+            return Err(InvalidSeason {
+                reason: "start must be before the end".to_string(),
+            });
+        }
+        if season_info.points_per_rank.is_empty() {
+            return Err(InvalidSeason {
+                reason: "no rank scores points".to_string(),
+            });
+        }
+        let mut epochs = season_info.epochs.clone();
+        epochs.sort();
+        epochs.dedup();
+        if epochs.len() != season_info.epochs.len() {
+            return Err(InvalidSeason {
+                reason: "epochs must not repeat".to_string(),
+            });
+        }
+        check_season_window(deps.storage, &season_info)?;
+        if let Some(season) = SEASONS.may_load(deps.storage, season_id)?
+            && season.settled_at.is_some()
+        {
+            return Err(SeasonSettled {});
+        }
+        SEASONS.save(
+            deps.storage,
+            season_id,
+            &Season {
+                season_info,
+                settled_at: None,
+                payout_dispatches: vec![],
+            },
+        )?;
+        Ok(Response::new()
+            .add_attribute("action", "set_season")
+            .add_attribute("season_id", season_id.to_string()))
+    }
+
+    pub fn settle_season(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        season_id: u64,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let state = STATE.load(deps.storage)?;
+        if state.owner != info.sender {
+            return Err(Unauthorized {});
+        }
+        let season = SEASONS.load(deps.storage, season_id)?;
+        if season.settled_at.is_some() {
+            return Err(SeasonSettled {});
+        }
+        if env.block.time.seconds() < season.season_info.end {
+            return Err(SeasonNotEnded {});
+        }
+        for epoch_id in &season.season_info.epochs {
+            match season_epoch(deps.storage, *epoch_id)?
+                .map(|epoch| epoch.status_at(env.block.time))
+            {
+                Some(EpochStatus::Settled | EpochStatus::Paid | EpochStatus::Cancelled) => {}
+                _ => {
+                    return Err(SeasonNotReady {
+                        epoch_id: epoch_id.to_string(),
+                    });
+                }
+            }
+        }
+        // Epochs activated or amended after the season was set are checked again.
+        check_season_window(deps.storage, &season.season_info)?;
+        let standings = season_standings(deps.storage, &season.season_info)?;
+        let total_points: u64 = standings.iter().map(|standing| standing.points).sum();
+        let winner_infos: Vec<WinnerInfo> = standings
+            .into_iter()
+            .map(|standing| WinnerInfo {
+                winner: standing.address,
+                claimable_amount: season
+                    .season_info
+                    .prize_amount
+                    .multiply_ratio(standing.points, total_points),
+                chain: Some(standing.chain),
+            })
+            .filter(|winner_info| !winner_info.claimable_amount.is_zero())
+            .collect();
+        if winner_infos.is_empty() {
+            return Err(SeasonNoPoints {});
+        }
+        // The approvers vote on the payouts as computed now.
+        if approval_enabled(deps.storage)? {
+            let action = ProposalAction::SettleSeason { season_id };
+            return create_proposal(deps, &env, Uint256::zero(), winner_infos, action);
+        }
+        pay_season(deps.storage, &env, state, season_id, winner_infos)
+    }
+
+    fn pay_season(
+        storage: &mut dyn Storage,
+        env: &Env,
+        state: State,
+        season_id: u64,
+        winner_infos: Vec<WinnerInfo>,
+    ) -> Result<Response<PalomaMsg>, ContractError> {
+        let mut season = SEASONS.load(storage, season_id)?;
+        // Another approved proposal may have settled it first.
+        if season.settled_at.is_some() {
+            return Err(SeasonSettled {});
+        }
+        // Settling happens once, so the season payout is not held back by the winners' epoch
+        // payout retries.
+        let (responses, dispatch_ids, _) =
+            dispatch_winners(storage, env, state, winner_infos, vec![], None)?;
+        season.settled_at = Some(env.block.time);
+        season.payout_dispatches = dispatch_ids;
+        SEASONS.save(storage, season_id, &season)?;
+        Ok(merge_responses("settle_season", responses)?
+            .add_attribute("season_id", season_id.to_string()))
+    }
+
+    /// Points per player over the season's settled epochs, highest first.
+    pub fn season_standings(
+        storage: &dyn Storage,
+        season_info: &SeasonInfo,
+    ) -> StdResult<Vec<SeasonStanding>> {
+        let mut epoch_ids = season_info.epochs.clone();
+        epoch_ids.sort();
+        let mut standings: BTreeMap<String, SeasonStanding> = BTreeMap::new();
+        for epoch_id in epoch_ids {
+            let Some(epoch) = season_epoch(storage, epoch_id)? else {
+                continue;
+            };
+            for (winner_info, points) in epoch.winners.iter().zip(&season_info.points_per_rank) {
+                if *points == 0 {
+                    continue;
+                }
+                let standing = standings
+                    .entry(winner_info.winner.clone())
+                    .or_insert_with(|| SeasonStanding {
+                        address: winner_info.winner.clone(),
+                        points: 0,
+                        chain: Chain::Eth,
+                    });
+                standing.points += points;
+                standing.chain = winner_info.chain.unwrap_or(Chain::Eth);
+            }
+        }
+        let mut standings: Vec<SeasonStanding> = standings.into_values().collect();
+        standings.sort_by(|a, b| {
+            b.points
+                .cmp(&a.points)
+                .then_with(|| a.address.cmp(&b.address))
+        });
+        Ok(standings)
+    }
+
+    /// Fails with `InvalidSeason` if a known epoch of the season starts before it or ends after
+    /// it. Epochs not scheduled yet are checked when the season is settled.
+    fn check_season_window(
+        storage: &dyn Storage,
+        season_info: &SeasonInfo,
+    ) -> Result<(), ContractError> {
+        let start = Uint256::from(season_info.start);
+        let end = Uint256::from(season_info.end);
+        for epoch_id in &season_info.epochs {
+            if let Some(epoch_info) =
+                season_epoch(storage, *epoch_id)?.and_then(|epoch| epoch.epoch_info)
+                && (epoch_info.competition_start < start || epoch_info.competition_end > end)
+            {
+                return Err(InvalidSeason {
+                    reason: format!("epoch {epoch_id} runs outside the season"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Epoch a season scores: the `arb` one, or the `eth` one for an epoch only run there.
+    fn season_epoch(storage: &dyn Storage, epoch_id: Uint256) -> StdResult<Option<Epoch>> {
+        for chain in [Chain::Arb, Chain::Eth] {
+            if let Some(epoch) = EPOCHS.may_load(storage, (chain.as_str(), epoch_id.to_string()))? {
+                return Ok(Some(epoch));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Current status of an epoch, `None` if it was never scheduled, activated or cancelled.
+    pub fn epoch_status(
+        storage: &dyn Storage,
+        env: &Env,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> StdResult<Option<EpochStatus>> {
+        Ok(EPOCHS
+            .may_load(storage, (chain.as_str(), epoch_id.to_string()))?
+            .map(|epoch| epoch.status_at(env.block.time)))
+    }
+
+    /// Funding is only taken for a known epoch that has not been paid out or cancelled.
+    fn check_fundable(
+        storage: &dyn Storage,
+        env: &Env,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> Result<(), ContractError> {
+        match epoch_status(storage, env, chain, epoch_id)? {
+            Some(EpochStatus::Scheduled | EpochStatus::Active | EpochStatus::Closed) => Ok(()),
+            Some(EpochStatus::Cancelled) => Err(EpochCancelled {}),
+            status => Err(EpochNotFundable {
+                status: status.map_or("none", |status| status.as_str()).to_string(),
+            }),
+        }
+    }
+
+    /// Moves an epoch to `to`, failing unless its lifecycle allows that from the current status.
+    fn transition_epoch(
+        storage: &mut dyn Storage,
+        env: &Env,
+        chain: Chain,
+        epoch_id: Uint256,
+        to: EpochStatus,
+        epoch_info: Option<EpochInfo>,
+    ) -> Result<(), ContractError> {
+        let key = (chain.as_str(), epoch_id.to_string());
+        let epoch = EPOCHS.may_load(storage, key.clone())?;
+        let from = epoch.as_ref().map(|epoch| epoch.status_at(env.block.time));
+        let legal = match to {
+            EpochStatus::Scheduled => matches!(from, None | Some(EpochStatus::Scheduled)),
+            EpochStatus::Active => matches!(
+                from,
+                None | Some(EpochStatus::Scheduled | EpochStatus::Active)
+            ),
+            EpochStatus::Closed => from == Some(EpochStatus::Active),
+            EpochStatus::Settled => {
+                matches!(from, Some(EpochStatus::Closed | EpochStatus::Settled))
+            }
+            EpochStatus::Paid => from == Some(EpochStatus::Settled),
+            EpochStatus::Cancelled => matches!(
+                from,
+                None | Some(EpochStatus::Scheduled | EpochStatus::Active | EpochStatus::Closed)
+            ),
+        };
+        if !legal {
+            return Err(InvalidEpochTransition {
+                from: from.map_or("none", |status| status.as_str()).to_string(),
+                to: to.as_str().to_string(),
+            });
+        }
+        let mut epoch = epoch.unwrap_or(Epoch {
+            epoch_info: None,
+            status: to,
+            updated_at: env.block.time,
+            cancel_reason: None,
+            amendments: vec![],
+            payout_dispatches: vec![],
+            winners: vec![],
+        });
+        // An active epoch is only sent again with the details it now has.
+        if from == Some(EpochStatus::Active)
+            && epoch_info.is_some()
+            && epoch_info != epoch.epoch_info
+        {
+            return Err(EpochInfoMismatch {});
+        }
+        if from == Some(EpochStatus::Scheduled) && to != EpochStatus::Scheduled {
+            EPOCH_QUEUE.remove(storage, (chain.as_str(), epoch_id.to_be_bytes().as_slice()));
+        }
+        if to == EpochStatus::Active
+            && let Some(epoch_info) = &epoch_info
+            && LATEST_EPOCHS
+                .may_load(storage, chain.as_str())?
+                .is_none_or(|latest| latest.epoch_id < epoch_id)
+        {
+            LATEST_EPOCHS.save(storage, chain.as_str(), epoch_info)?;
+        }
+        if epoch_info.is_some() {
+            epoch.epoch_info = epoch_info;
+        }
+        epoch.status = to;
+        epoch.updated_at = env.block.time;
+        EPOCHS.save(storage, key, &epoch)?;
+        Ok(())
+    }
+
+    pub fn validate_retry_policy(retry_policy: &RetryPolicy) -> Result<(), ContractError> {
+        if retry_policy.max_attempts == 0
+            || retry_policy.base_delay > retry_policy.max_delay
+            || retry_policy.multiplier < Decimal::one()
+        {
+            return Err(InvalidRetryPolicy {});
+        }
+        Ok(())
+    }
+
+    /// Canonical `WITHDRAW_TIMESTAMP` key for a winner address or epoch id on `chain`, by default
+    /// `eth` for winners and `arb` for epochs. Winner keys also need the epoch of the payout.
+    pub fn retry_key(
+        action: RetryAction,
+        key: &str,
+        chain: Option<Chain>,
+        epoch_id: Option<Uint256>,
+    ) -> Result<(String, String), ContractError> {
+        match action {
+            RetryAction::SetWinner => Ok(winner_retry_key(
+                chain.unwrap_or(Chain::Eth),
+                &evm::normalize_address(key)?,
+                epoch_id.ok_or(EpochIdRequired {})?,
+            )),
+            RetryAction::SetActiveEpoch => Ok(epoch_retry_key(
+                chain.unwrap_or(Chain::Arb),
+                Uint256::from_str(key)?,
+            )),
+        }
+    }
+
+    /// Attempts are counted per chain; the chain each action was first sent to, `eth` for
+    /// winners and `arb` for epochs, keeps the plain action name.
+    pub fn chain_retry_key(action: RetryAction, chain: Chain, key: String) -> (String, String) {
+        let name = match (action, chain) {
+            (RetryAction::SetWinner, Chain::Eth) | (RetryAction::SetActiveEpoch, Chain::Arb) => {
+                action.as_str().to_string()
+            }
+            _ => format!("{}:{}", action.as_str(), chain.as_str()),
+        };
+        (key, name)
+    }
+
+    fn epoch_retry_key(chain: Chain, epoch_id: Uint256) -> (String, String) {
+        chain_retry_key(RetryAction::SetActiveEpoch, chain, epoch_id.to_string())
+    }
+
+    /// A winner's attempts are counted per epoch, so an earlier epoch's payout retries do not
+    /// use up the next one's.
+    fn winner_retry_key(chain: Chain, winner: &str, epoch_id: Uint256) -> (String, String) {
+        chain_retry_key(
+            RetryAction::SetWinner,
+            chain,
+            format!("{winner}:{epoch_id}"),
+        )
+    }
+
+    /// Whether a payout dispatch of the epoch other than `dispatch_id` is still pending.
+    fn payouts_pending(
+        storage: &dyn Storage,
+        chain: Chain,
+        epoch_id: Uint256,
+        dispatch_id: u64,
+    ) -> StdResult<bool> {
+        let epoch = EPOCHS.load(storage, (chain.as_str(), epoch_id.to_string()))?;
+        for id in epoch.payout_dispatches {
+            if id != dispatch_id && DISPATCHES.load(storage, id)?.status == DispatchStatus::Pending
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Retry policy of `action`, falling back to the default one.
+    pub fn retry_policy(storage: &dyn Storage, action: &str) -> StdResult<RetryPolicy> {
+        match RETRY_POLICIES.may_load(storage, action)? {
+            Some(retry_policy) => Ok(retry_policy),
+            None => Ok(STATE.load(storage)?.retry_policy),
+        }
+    }
+
+    /// Whether `key` was never dispatched or may be dispatched again under `policy`.
+    fn retry_ready(
+        storage: &dyn Storage,
+        env: &Env,
+        key: (String, String),
+        policy: &RetryPolicy,
+    ) -> StdResult<bool> {
+        Ok(WITHDRAW_TIMESTAMP
+            .may_load(storage, key)?
+            .is_none_or(|retry| retry.status(policy, env.block.time) == RetryStatus::Ready))
+    }
+
+    fn record_attempt(
+        storage: &mut dyn Storage,
+        env: &Env,
+        key: (String, String),
+    ) -> StdResult<()> {
+        let attempts = WITHDRAW_TIMESTAMP
+            .may_load(storage, key.clone())?
+            .map_or(0, |retry| retry.attempts);
+        WITHDRAW_TIMESTAMP.save(
+            storage,
+            key,
+            &RetryState {
+                timestamp: env.block.time,
+                attempts: attempts + 1,
+                reopened: false,
+            },
+        )
+    }
+
+    /// Rewrites every winner address into its EIP-55 form so storage keys are case-insensitive.
+    fn normalize_winner_infos(
+        winner_infos: Vec<WinnerInfo>,
+    ) -> Result<Vec<WinnerInfo>, ContractError> {
+        winner_infos
+            .into_iter()
+            .map(|winner_info| {
+                Ok(WinnerInfo {
+                    winner: evm::normalize_address(&winner_info.winner)?,
+                    ..winner_info
+                })
+            })
+            .collect()
+    }
+
+    /// `(address,uint256)` tuple for a `WinnerInfo`.
+    fn winner_info_token(winner_info: &WinnerInfo) -> Result<Token, ContractError> {
+        Ok(Token::Tuple(vec![
+            Token::Address(evm::parse_address(&winner_info.winner)?),
+            Token::Uint(Uint::from_big_endian(
+                &winner_info.claimable_amount.to_be_bytes(),
+            )),
+        ]))
+    }
+
+    /// `(address,uint256,string)` tuple an attestor signs for a `WinnerInfo`, ending with the chain
+    /// the winner is paid on.
+    fn attested_winner_token(winner_info: &WinnerInfo) -> Result<Token, ContractError> {
+        Ok(Token::Tuple(vec![
+            Token::Address(evm::parse_address(&winner_info.winner)?),
+            Token::Uint(Uint::from_big_endian(
+                &winner_info.claimable_amount.to_be_bytes(),
+            )),
+            Token::String(winner_info.chain.unwrap_or(Chain::Eth).as_str().to_string()),
+        ]))
+    }
+
+    /// `(uint256,uint256,uint256,uint256,uint256)` tuple for an `EpochInfo`.
+    fn epoch_info_token(epoch_info: &EpochInfo) -> Token {
+        Token::Tuple(
+            [
+                epoch_info.epoch_id,
+                epoch_info.competition_start,
+                epoch_info.competition_end,
+                epoch_info.entry_cnt,
+                epoch_info.prize_amount,
+            ]
+            .iter()
+            .map(|value| Token::Uint(Uint::from_big_endian(&value.to_be_bytes())))
+            .collect(),
+        )
+    }
+}
+/// Records whether the Paloma module accepted a dispatch; the submessage id is the dispatch id.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response<PalomaMsg>, ContractError> {
+    let state = STATE.load(deps.storage)?;
+    let mut dispatch = DISPATCHES.load(deps.storage, msg.id)?;
+    let response = Response::new()
+        .add_attribute("action", "dispatch_reply")
+        .add_attribute("dispatch_id", msg.id.to_string());
+    let response = match msg.result {
+        SubMsgResult::Ok(_) => {
+            dispatch.submitted = Some(true);
+            response.add_attribute("submitted", "true")
+        }
+        SubMsgResult::Err(err) => {
+            dispatch.submitted = Some(false);
+            dispatch.submit_error = Some(err.clone());
+            dispatch.status = DispatchStatus::Failed;
+            if state.rollback_on_submit_failure {
+                for key in dispatch.retry_keys.iter() {
+                    // The payload never left this chain, so the attempt doesn't count.
+                    if let Some(mut retry) = WITHDRAW_TIMESTAMP
+                        .may_load(deps.storage, key.clone())?
+                        .filter(|retry| retry.timestamp == dispatch.last_sent_at)
+                    {
+                        retry.attempts = retry.attempts.saturating_sub(1);
+                        retry.reopened = true;
+                        WITHDRAW_TIMESTAMP.save(deps.storage, key.clone(), &retry)?;
+                    }
+                }
+            }
+            response
+                .add_attribute("submitted", "false")
+                .add_attribute("error", err)
+        }
+    };
+    DISPATCHES.save(deps.storage, msg.id, &dispatch)?;
+    Ok(response)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::GetEthJobId {} => to_json_binary(&query::get_eth_job_id(deps)?),
+        QueryMsg::GetArbJobId {} => to_json_binary(&query::get_arb_job_id(deps)?),
+        QueryMsg::GetStagedWinnerList { epoch_id } => {
+            to_json_binary(&query::get_staged_winner_list(deps, epoch_id)?)
+        }
+        QueryMsg::GetApprovalConfig {} => to_json_binary(&query::get_approval_config(deps)?),
+        QueryMsg::GetWinnerListProposal { proposal_id } => {
+            to_json_binary(&query::get_winner_list_proposal(deps, env, proposal_id)?)
+        }
+        QueryMsg::GetAttestors {} => to_json_binary(&query::get_attestors(deps)?),
+        QueryMsg::GetAllowedCalls { chain } => {
+            to_json_binary(&query::get_allowed_calls(deps, chain)?)
+        }
+        QueryMsg::GetTargetAbi { chain } => to_json_binary(&query::get_target_abi(deps, chain)?),
+        QueryMsg::DecodePayload { chain, payload } => {
+            to_json_binary(&query::decode_payload(deps, chain, payload)?)
+        }
+        QueryMsg::GetDispatch { dispatch_id } => {
+            to_json_binary(&query::get_dispatch(deps, dispatch_id)?)
+        }
+        QueryMsg::GetRoleMembers { role } => to_json_binary(&query::get_role_members(deps, role)?),
+        QueryMsg::GetPalomaMsgVersion {} => {
+            to_json_binary(&STATE.load(deps.storage)?.paloma_msg_version)
+        }
+        QueryMsg::GetChainConfig { chain } => {
+            to_json_binary(&CHAIN_CONFIGS.load(deps.storage, chain.as_str())?)
+        }
+        QueryMsg::GetFeeConfig { chain } => {
+            to_json_binary(&FEE_CONFIGS.load(deps.storage, chain.as_str())?)
+        }
+        QueryMsg::GetFeeTotals {} => to_json_binary(&query::get_fee_totals(deps)?),
+        QueryMsg::GetEpochTemplate { chain } => {
+            to_json_binary(&EPOCH_TEMPLATES.load(deps.storage, chain.as_str())?)
+        }
+        QueryMsg::GetPrizePool { chain, epoch_id } => {
+            to_json_binary(&query::get_prize_pool(deps, chain, epoch_id)?)
+        }
+        QueryMsg::GetEpoch { chain, epoch_id } => {
+            to_json_binary(&query::get_epoch(deps, env, chain, epoch_id)?)
+        }
+        QueryMsg::GetEpochQueue { chain } => to_json_binary(&query::get_epoch_queue(deps, chain)?),
+        QueryMsg::Leaderboard {
+            chain,
+            order_by,
+            limit,
+            start_after,
+        } => to_json_binary(&query::leaderboard(
+            deps,
+            chain,
+            order_by,
+            limit,
+            start_after,
+        )?),
+        QueryMsg::PlayerStats { address } => to_json_binary(&query::player_stats(deps, address)?),
+        QueryMsg::GetSeason { season_id } => to_json_binary(&query::get_season(deps, season_id)?),
+        QueryMsg::GetSponsorBalances {
+            chain,
+            epoch_id,
+            sponsor,
+        } => to_json_binary(&query::get_sponsor_balances(
+            deps, env, chain, epoch_id, sponsor,
+        )?),
+        QueryMsg::GetRetryPolicy { action } => {
+            to_json_binary(&query::get_retry_policy(deps, action)?)
+        }
+        QueryMsg::GetRetryState {
+            action,
+            key,
+            chain,
+            epoch_id,
+        } => to_json_binary(&query::get_retry_state(
+            deps, env, action, key, chain, epoch_id,
+        )?),
+    }
+}
+
+pub mod query {
+    use super::*;
+    use crate::msg::{
+        AbiValue, AllowedCallsResponse, ApprovalConfigResponse, AttestorsResponse, Chain,
+        DecodePayloadResponse, DispatchResponse, EpochInfo, EpochQueueResponse, EpochResponse,
+        EpochStatus, FeeTotal, FeeTotalsResponse, LeaderboardOrder, LeaderboardResponse,
+        PlayerStats, PlayerStatsResponse, PrizePoolResponse, ProposalStatus, RetryAction,
+        RetryPolicy, RetryStateResponse, RetryStatus, Role, RoleMembersResponse, SeasonResponse,
+        SponsorBalance, SponsorBalancesResponse, StagedWinnerListResponse, TargetAbiResponse,
+        WinnerInfo, WinnerListProposalResponse,
+    };
+    use crate::state;
+    use crate::state::{
+        ALL_CHAINS, APPROVAL_CONFIG, ATTESTORS, CALL_ALLOWLIST, DISPATCHES, EPOCHS, EPOCH_QUEUE,
+        FEE_TOTALS, PRIZE_FUNDING, PROPOSALS, ROLES, SEASONS, SPONSORSHIPS, TARGET_ABIS,
+        WINNER_LIST_STAGING, WITHDRAW_TIMESTAMP,
+    };
+    use cosmwasm_std::Coin;
+    use cosmwasm_std::Order;
+    use cosmwasm_std::StdError;
+    use cw_storage_plus::Bound;
+
+    pub fn get_eth_job_id(deps: Deps) -> StdResult<GetJobIdResponse> {
+        let state = STATE.load(deps.storage)?;
+        Ok(GetJobIdResponse {
+            job_id: state.job_eth_id,
+        })
     }
 
     pub fn get_arb_job_id(deps: Deps) -> StdResult<GetJobIdResponse> {
@@ -491,4 +3325,376 @@ pub mod query {
             job_id: state.job_arb_id,
         })
     }
+
+    pub fn get_staged_winner_list(
+        deps: Deps,
+        epoch_id: Uint256,
+    ) -> StdResult<StagedWinnerListResponse> {
+        let staging = WINNER_LIST_STAGING.may_load(deps.storage, epoch_id.to_string())?;
+        Ok(StagedWinnerListResponse {
+            epoch_id,
+            count: staging.as_ref().map(|s| s.count),
+            total: staging.map(|s| s.total),
+        })
+    }
+
+    pub fn get_approval_config(deps: Deps) -> StdResult<ApprovalConfigResponse> {
+        let config = APPROVAL_CONFIG.may_load(deps.storage)?.unwrap_or_default();
+        Ok(ApprovalConfigResponse {
+            approvers: config.approvers.into_iter().map(String::from).collect(),
+            threshold: config.threshold,
+            proposal_ttl: config.proposal_ttl,
+        })
+    }
+
+    pub fn get_winner_list_proposal(
+        deps: Deps,
+        env: Env,
+        proposal_id: u64,
+    ) -> StdResult<WinnerListProposalResponse> {
+        let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        let status = if proposal.status == ProposalStatus::Pending
+            && proposal.expires_at <= env.block.time
+        {
+            ProposalStatus::Expired
+        } else {
+            proposal.status
+        };
+        Ok(WinnerListProposalResponse {
+            proposal_id,
+            action: proposal.action,
+            epoch_id: proposal.epoch_id,
+            winner_infos: proposal.winner_infos,
+            hash: proposal.hash,
+            approvals: proposal.approvals.into_iter().map(String::from).collect(),
+            rejections: proposal.rejections.into_iter().map(String::from).collect(),
+            expires_at: proposal.expires_at,
+            status,
+        })
+    }
+
+    pub fn get_attestors(deps: Deps) -> StdResult<AttestorsResponse> {
+        let pubkeys = ATTESTORS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|pubkey| pubkey.map(Binary))
+            .collect::<StdResult<Vec<Binary>>>()?;
+        Ok(AttestorsResponse { pubkeys })
+    }
+
+    pub fn get_allowed_calls(deps: Deps, chain: Chain) -> StdResult<AllowedCallsResponse> {
+        let functions = CALL_ALLOWLIST
+            .prefix(chain.as_str())
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()?;
+        Ok(AllowedCallsResponse { functions })
+    }
+
+    pub fn get_target_abi(deps: Deps, chain: Chain) -> StdResult<TargetAbiResponse> {
+        let abi = TARGET_ABIS.may_load(deps.storage, chain.as_str())?;
+        let contract = abi::load_contract(deps.storage, chain)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        Ok(TargetAbiResponse {
+            abi,
+            functions: contract
+                .functions()
+                .map(|function| function.signature())
+                .collect(),
+        })
+    }
+
+    pub fn decode_payload(
+        deps: Deps,
+        chain: Chain,
+        payload: Binary,
+    ) -> StdResult<DecodePayloadResponse> {
+        let (function, tokens) = abi::known_functions(deps.storage, chain)
+            .and_then(|functions| abi::decode_call(&functions, &payload))
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let args: Vec<AbiValue> = tokens.into_iter().map(abi::detokenize).collect();
+        let (winner_infos, epoch_info) = match (function.name.as_str(), args.as_slice()) {
+            ("set_winner_list", [AbiValue::Array(winners)]) => (
+                winners
+                    .iter()
+                    .map(|winner| match winner {
+                        AbiValue::Tuple(fields) => match fields.as_slice() {
+                            [AbiValue::Address(winner), AbiValue::Uint(claimable_amount)] => {
+                                Some(WinnerInfo {
+                                    winner: winner.clone(),
+                                    claimable_amount: *claimable_amount,
+                                    chain: Some(chain),
+                                })
+                            }
+                            _ => None,
+                        },
+                        _ => None,
+                    })
+                    .collect(),
+                None,
+            ),
+            ("set_active_epoch", [AbiValue::Tuple(fields)]) => match fields.as_slice() {
+                [AbiValue::Uint(epoch_id), AbiValue::Uint(competition_start), AbiValue::Uint(competition_end), AbiValue::Uint(entry_cnt), AbiValue::Uint(prize_amount)] => {
+                    (
+                        None,
+                        Some(EpochInfo {
+                            epoch_id: *epoch_id,
+                            competition_start: *competition_start,
+                            competition_end: *competition_end,
+                            entry_cnt: *entry_cnt,
+                            prize_amount: *prize_amount,
+                        }),
+                    )
+                }
+                _ => (None, None),
+            },
+            _ => (None, None),
+        };
+        Ok(DecodePayloadResponse {
+            function: function.signature(),
+            args,
+            winner_infos,
+            epoch_info,
+        })
+    }
+
+    pub fn get_dispatch(deps: Deps, dispatch_id: u64) -> StdResult<DispatchResponse> {
+        let dispatch = DISPATCHES.load(deps.storage, dispatch_id)?;
+        Ok(DispatchResponse {
+            dispatch_id,
+            chain: dispatch.chain,
+            action: dispatch.action,
+            job_id: dispatch.job_id,
+            status: dispatch.status,
+            created_at: dispatch.created_at,
+            last_sent_at: dispatch.last_sent_at,
+            resend_count: dispatch.resend_count,
+            payload: dispatch.payload,
+            submitted: dispatch.submitted,
+            submit_error: dispatch.submit_error,
+            evm_tx_hash: dispatch.evm_tx_hash,
+            error: dispatch.error,
+            reported_at: dispatch.reported_at,
+        })
+    }
+
+    pub fn get_role_members(deps: Deps, role: Role) -> StdResult<RoleMembersResponse> {
+        let members = ROLES
+            .prefix(role.as_str())
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|member| member.map(String::from))
+            .collect::<StdResult<Vec<String>>>()?;
+        Ok(RoleMembersResponse { members })
+    }
+
+    pub fn get_retry_policy(deps: Deps, action: Option<RetryAction>) -> StdResult<RetryPolicy> {
+        match action {
+            Some(action) => execute::retry_policy(deps.storage, action.as_str()),
+            None => Ok(STATE.load(deps.storage)?.retry_policy),
+        }
+    }
+
+    pub fn get_retry_state(
+        deps: Deps,
+        env: Env,
+        action: RetryAction,
+        key: String,
+        chain: Option<Chain>,
+        epoch_id: Option<Uint256>,
+    ) -> StdResult<RetryStateResponse> {
+        let policy = execute::retry_policy(deps.storage, action.as_str())?;
+        let key = execute::retry_key(action, &key, chain, epoch_id)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let retry = WITHDRAW_TIMESTAMP.may_load(deps.storage, key)?;
+        Ok(match retry {
+            Some(retry) => RetryStateResponse {
+                attempts: retry.attempts,
+                last_attempt_at: Some(retry.timestamp),
+                next_attempt_at: Some(retry.next_attempt_at(&policy)),
+                status: retry.status(&policy, env.block.time),
+            },
+            None => RetryStateResponse {
+                attempts: 0,
+                last_attempt_at: None,
+                next_attempt_at: None,
+                status: RetryStatus::Ready,
+            },
+        })
+    }
+
+    pub fn get_prize_pool(
+        deps: Deps,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> StdResult<PrizePoolResponse> {
+        let key = epoch_id.to_string();
+        let committed = EPOCHS
+            .may_load(deps.storage, (chain.as_str(), key.clone()))?
+            .and_then(|epoch| epoch.epoch_info)
+            .map(|epoch_info| epoch_info.prize_amount);
+        let funded = PRIZE_FUNDING
+            .prefix((chain.as_str(), key))
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+            .collect::<StdResult<Vec<Coin>>>()?;
+        Ok(PrizePoolResponse {
+            chain,
+            epoch_id,
+            committed,
+            funded,
+        })
+    }
+
+    const DEFAULT_LIMIT: u32 = 10;
+    const MAX_LIMIT: u32 = 30;
+
+    pub fn leaderboard(
+        deps: Deps,
+        chain: Option<Chain>,
+        order_by: LeaderboardOrder,
+        limit: Option<u32>,
+        start_after: Option<String>,
+    ) -> StdResult<LeaderboardResponse> {
+        let bucket = chain.map_or(ALL_CHAINS, |chain| chain.as_str());
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let stats = state::player_stats();
+        let after = match start_after {
+            Some(address) => {
+                let address = evm::normalize_address(&address)
+                    .map_err(|err| StdError::generic_err(err.to_string()))?;
+                Some(stats.load(deps.storage, (bucket, &address))?)
+            }
+            None => None,
+        };
+        let players = match order_by {
+            LeaderboardOrder::TotalWon => {
+                let max = after.map(|player| {
+                    Bound::exclusive((
+                        player.total_won.to_be_bytes().to_vec(),
+                        (bucket.to_string(), player.address),
+                    ))
+                });
+                stats
+                    .idx
+                    .total_won
+                    .sub_prefix(bucket.to_string())
+                    .range(deps.storage, None, max, Order::Descending)
+                    .take(limit)
+                    .map(|item| item.map(|(_, player)| player))
+                    .collect::<StdResult<Vec<PlayerStats>>>()?
+            }
+            LeaderboardOrder::Wins => {
+                let max = after.map(|player| {
+                    Bound::exclusive((player.wins, (bucket.to_string(), player.address)))
+                });
+                stats
+                    .idx
+                    .wins
+                    .sub_prefix(bucket.to_string())
+                    .range(deps.storage, None, max, Order::Descending)
+                    .take(limit)
+                    .map(|item| item.map(|(_, player)| player))
+                    .collect::<StdResult<Vec<PlayerStats>>>()?
+            }
+        };
+        Ok(LeaderboardResponse { players })
+    }
+
+    pub fn player_stats(deps: Deps, address: String) -> StdResult<PlayerStatsResponse> {
+        let address = evm::normalize_address(&address)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let stats = state::player_stats();
+        let mut chains: Vec<PlayerStats> = vec![];
+        for chain in [Chain::Eth, Chain::Arb] {
+            if let Some(player) = stats.may_load(deps.storage, (chain.as_str(), &address))? {
+                chains.push(player);
+            }
+        }
+        Ok(PlayerStatsResponse {
+            overall: stats.may_load(deps.storage, (ALL_CHAINS, &address))?,
+            address,
+            chains,
+        })
+    }
+
+    pub fn get_fee_totals(deps: Deps) -> StdResult<FeeTotalsResponse> {
+        let mut totals: Vec<FeeTotal> = vec![];
+        for chain in [Chain::Eth, Chain::Arb] {
+            if let Some(amount) = FEE_TOTALS.may_load(deps.storage, chain.as_str())? {
+                totals.push(FeeTotal { chain, amount });
+            }
+        }
+        Ok(FeeTotalsResponse { totals })
+    }
+
+    pub fn get_season(deps: Deps, season_id: u64) -> StdResult<SeasonResponse> {
+        let season = SEASONS.load(deps.storage, season_id)?;
+        Ok(SeasonResponse {
+            season_id,
+            standings: execute::season_standings(deps.storage, &season.season_info)?,
+            season_info: season.season_info,
+            settled_at: season.settled_at,
+            payout_dispatches: season.payout_dispatches,
+        })
+    }
+
+    pub fn get_epoch(
+        deps: Deps,
+        env: Env,
+        chain: Chain,
+        epoch_id: Uint256,
+    ) -> StdResult<EpochResponse> {
+        let epoch = EPOCHS.load(deps.storage, (chain.as_str(), epoch_id.to_string()))?;
+        Ok(EpochResponse {
+            chain,
+            epoch_id,
+            status: epoch.status_at(env.block.time),
+            epoch_info: epoch.epoch_info,
+            updated_at: epoch.updated_at,
+            cancel_reason: epoch.cancel_reason,
+            amendments: epoch.amendments,
+            payout_dispatches: epoch.payout_dispatches,
+            winners: epoch.winners,
+        })
+    }
+
+    pub fn get_epoch_queue(deps: Deps, chain: Chain) -> StdResult<EpochQueueResponse> {
+        let epochs = EPOCH_QUEUE
+            .prefix(chain.as_str())
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, epoch_info)| epoch_info))
+            .collect::<StdResult<Vec<EpochInfo>>>()?;
+        Ok(EpochQueueResponse { chain, epochs })
+    }
+
+    pub fn get_sponsor_balances(
+        deps: Deps,
+        env: Env,
+        chain: Chain,
+        epoch_id: Uint256,
+        sponsor: Option<String>,
+    ) -> StdResult<SponsorBalancesResponse> {
+        let key = epoch_id.to_string();
+        let sponsor = sponsor
+            .map(|sponsor| deps.api.addr_validate(&sponsor))
+            .transpose()?;
+        let mut balances: Vec<SponsorBalance> = vec![];
+        for item in SPONSORSHIPS
+            .sub_prefix((chain.as_str(), key.clone()))
+            .range(deps.storage, None, None, Order::Ascending)
+        {
+            let ((token, addr), amount) = item?;
+            if sponsor.as_ref().is_some_and(|sponsor| *sponsor != addr) {
+                continue;
+            }
+            balances.push(SponsorBalance {
+                sponsor: addr.to_string(),
+                token: token.to_string(),
+                amount,
+            });
+        }
+        Ok(SponsorBalancesResponse {
+            cancelled: execute::epoch_status(deps.storage, &env, chain, epoch_id)?
+                == Some(EpochStatus::Cancelled),
+            balances,
+        })
+    }
 }
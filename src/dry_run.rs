@@ -0,0 +1,66 @@
+use cosmwasm_std::{Order, Record, Storage};
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+/// Storage that reads through to `base` but keeps every write in memory, so a dry run sees its
+/// own writes without persisting any of them.
+pub struct DryRunStorage<'a> {
+    base: &'a dyn Storage,
+    /// Pending writes; `None` marks a removed key.
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> DryRunStorage<'a> {
+    pub fn new(base: &'a dyn Storage) -> Self {
+        DryRunStorage {
+            base,
+            writes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Storage for DryRunStorage<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        if let (Some(start), Some(end)) = (start, end)
+            && start >= end
+        {
+            return Box::new(std::iter::empty());
+        }
+        let mut records: BTreeMap<Vec<u8>, Vec<u8>> =
+            self.base.range(start, end, Order::Ascending).collect();
+        let bounds = (
+            start.map_or(Bound::Unbounded, Bound::Included),
+            end.map_or(Bound::Unbounded, Bound::Excluded),
+        );
+        for (key, value) in self.writes.range::<[u8], _>(bounds) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
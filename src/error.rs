@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError, Uint256, VerificationError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,11 +6,182 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("{0}")]
+    Verification(#[from] VerificationError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
     #[error("AllPending")]
     AllPending {},
+
+    #[error("StagingExists")]
+    StagingExists {},
+
+    #[error("StagingNotFound")]
+    StagingNotFound {},
+
+    #[error("StagingMismatch: staged {count} winners totalling {total}")]
+    StagingMismatch { count: u32, total: Uint256 },
+
+    #[error("InvalidThreshold")]
+    InvalidThreshold {},
+
+    #[error("ApprovalRequired")]
+    ApprovalRequired {},
+
+    #[error("ApprovalNotEnabled")]
+    ApprovalNotEnabled {},
+
+    #[error("ProposalClosed")]
+    ProposalClosed {},
+
+    #[error("ProposalExpired")]
+    ProposalExpired {},
+
+    #[error("AlreadyVoted")]
+    AlreadyVoted {},
+
+    #[error("InvalidPubkey")]
+    InvalidPubkey {},
+
+    #[error("InvalidAttestation")]
+    InvalidAttestation {},
+
+    #[error("AttestationUsed")]
+    AttestationUsed {},
+
+    #[error("InvalidAddress: {address}")]
+    InvalidAddress { address: String },
+
+    #[error("InvalidChecksum: {address}")]
+    InvalidChecksum { address: String },
+
+    #[error("ZeroAddress")]
+    ZeroAddress {},
+
+    #[error("InvalidSignature: {signature}")]
+    InvalidSignature { signature: String },
+
+    #[error("InvalidArguments: {reason}")]
+    InvalidArguments { reason: String },
+
+    #[error("CallNotAllowed: {function}")]
+    CallNotAllowed { function: String },
+
+    #[error("InvalidAbi: {reason}")]
+    InvalidAbi { reason: String },
+
+    #[error("FunctionNotFound: {function}")]
+    FunctionNotFound { function: String },
+
+    #[error("ManagedFunction: {function} is only sent through its own message")]
+    ManagedFunction { function: String },
+
+    #[error("UnknownSelector")]
+    UnknownSelector {},
+
+    #[error("DispatchNotPending")]
+    DispatchNotPending {},
+
+    #[error("DispatchConfirmed")]
+    DispatchConfirmed {},
+
+    #[error("DispatchSuperseded")]
+    DispatchSuperseded {},
+
+    #[error("InvalidRetryPolicy")]
+    InvalidRetryPolicy {},
+
+    #[error("EpochIdRequired")]
+    EpochIdRequired {},
+
+    #[error("RetryExhausted")]
+    RetryExhausted {},
+
+    #[error("ChainNotConfigured: {chain}")]
+    ChainNotConfigured { chain: String },
+
+    #[error("InvalidFunds: send exactly one coin")]
+    InvalidFunds {},
+
+    #[error("SkywayNotSupported")]
+    SkywayNotSupported {},
+
+    #[error("EpochCancelled")]
+    EpochCancelled {},
+
+    #[error("EpochNotCancelled")]
+    EpochNotCancelled {},
+
+    #[error("EpochNotPaid")]
+    EpochNotPaid {},
+
+    #[error("EpochNotFundable: {status}")]
+    EpochNotFundable { status: String },
+
+    #[error("InvalidEpochTransition: {from} to {to}")]
+    InvalidEpochTransition { from: String, to: String },
+
+    #[error("EpochInfoMismatch")]
+    EpochInfoMismatch {},
+
+    #[error("InvalidEpochWindow: start must be in the future and before the end")]
+    InvalidEpochWindow {},
+
+    #[error("EpochOutOfOrder: epoch ids must increase with competition_start")]
+    EpochOutOfOrder {},
+
+    #[error("EpochOverlap: {epoch_id}")]
+    EpochOverlap { epoch_id: String },
+
+    #[error("JobNotConfigured: {chain}")]
+    JobNotConfigured { chain: String },
+
+    #[error("NoChainsSelected")]
+    NoChainsSelected {},
+
+    #[error("EpochNotActive")]
+    EpochNotActive {},
+
+    #[error("InvalidAmendment: the prize may only increase and the end only extend")]
+    InvalidAmendment {},
+
+    #[error("EpochTemplateNotConfigured: {chain}")]
+    EpochTemplateNotConfigured { chain: String },
+
+    #[error("InvalidEpochTemplate: duration must be above zero")]
+    InvalidEpochTemplate {},
+
+    #[error("InvalidSeason: {reason}")]
+    InvalidSeason { reason: String },
+
+    #[error("SeasonNotEnded")]
+    SeasonNotEnded {},
+
+    #[error("SeasonNotReady: epoch {epoch_id} is not settled")]
+    SeasonNotReady { epoch_id: String },
+
+    #[error("SeasonSettled")]
+    SeasonSettled {},
+
+    #[error("SeasonNoPoints")]
+    SeasonNoPoints {},
+
+    #[error("InvalidFee: fee_bps must not exceed 10000")]
+    InvalidFee {},
+
+    #[error("DuplicateWinner: {winner} is listed twice on {chain}")]
+    DuplicateWinner { winner: String, chain: String },
+
+    #[error("PrizePoolExceeded: winners on {chain} may claim at most {available}")]
+    PrizePoolExceeded { chain: String, available: String },
+
+    #[error("NothingToRefund")]
+    NothingToRefund {},
     // Add any other custom errors you like here.
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
 }
@@ -0,0 +1,58 @@
+use crate::ContractError;
+use ethabi::Address;
+use sha3::{Digest, Keccak256};
+
+/// Parses a `0x`-prefixed EVM address. Mixed-case input must carry a valid EIP-55 checksum,
+/// and the zero address is rejected.
+pub fn parse_address(address: &str) -> Result<Address, ContractError> {
+    let hex = address
+        .strip_prefix("0x")
+        .filter(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .ok_or_else(|| ContractError::InvalidAddress {
+            address: address.to_string(),
+        })?;
+    let mut bytes = [0u8; 20];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    let parsed = Address::from(bytes);
+    if parsed.is_zero() {
+        return Err(ContractError::ZeroAddress {});
+    }
+    let mixed_case =
+        hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+    if mixed_case && to_checksum(&parsed) != address {
+        return Err(ContractError::InvalidChecksum {
+            address: address.to_string(),
+        });
+    }
+    Ok(parsed)
+}
+
+/// Canonical EIP-55 form of an address string, used for storage keys and attributes.
+pub fn normalize_address(address: &str) -> Result<String, ContractError> {
+    Ok(to_checksum(&parse_address(address)?))
+}
+
+/// EIP-55 mixed-case checksum encoding.
+pub fn to_checksum(address: &Address) -> String {
+    let lower: String = address
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    let hash = Keccak256::digest(lower.as_bytes());
+    let checksummed: String = lower
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("0x{checksummed}")
+}
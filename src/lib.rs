@@ -1,5 +1,8 @@
+pub mod abi;
 pub mod contract;
+mod dry_run;
 mod error;
+pub mod evm;
 pub mod msg;
 pub mod state;
 
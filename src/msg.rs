@@ -1,9 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, CustomMsg, Uint256};
+use cosmwasm_std::{Binary, Coin, CustomMsg, Decimal, Int256, Timestamp, Uint128, Uint256};
+use cw20::Cw20ReceiveMsg;
 
 #[cw_serde]
 pub struct InstantiateMsg {
-    pub retry_delay: u64,
+    /// Default retry policy for every action.
+    pub retry_policy: RetryPolicy,
+    /// Undo the retry attempts of a dispatch the Paloma module rejects. Defaults to `false`.
+    pub rollback_on_submit_failure: Option<bool>,
+    /// Defaults to `v1`.
+    pub paloma_msg_version: Option<PalomaMsgVersion>,
     pub job_arb_id: String,
     pub job_eth_id: String,
     pub creator: String,
@@ -11,12 +17,24 @@ pub struct InstantiateMsg {
 }
 
 #[cw_serde]
+pub struct MigrateMsg {
+    /// Attempt limit of the retry policy made from the `retry_delay` of a contract stored before
+    /// retry policies. Defaults to `u32::MAX`, keeping retries unlimited.
+    pub max_attempts: Option<u32>,
+}
+
+#[cw_serde]
+#[derive(Eq)]
 pub struct WinnerInfo {
     pub winner: String,
     pub claimable_amount: Uint256,
+    /// Chain the winner is paid on, `eth` by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<Chain>,
 }
 
 #[cw_serde]
+#[derive(Eq)]
 pub struct EpochInfo {
     pub epoch_id: Uint256,
     pub competition_start: Uint256,
@@ -25,14 +43,460 @@ pub struct EpochInfo {
     pub prize_amount: Uint256,
 }
 
+/// Lifecycle of an epoch: `Scheduled` → `Active` → `Closed` → `Settled` → `Paid`, or `Cancelled`
+/// before settlement.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum EpochStatus {
+    Scheduled,
+    Active,
+    /// The competition has ended, or the owner closed it early.
+    Closed,
+    /// The winner list has been dispatched.
+    Settled,
+    /// A relayer reported the winner list executed.
+    Paid,
+    Cancelled,
+}
+
+impl EpochStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EpochStatus::Scheduled => "scheduled",
+            EpochStatus::Active => "active",
+            EpochStatus::Closed => "closed",
+            EpochStatus::Settled => "settled",
+            EpochStatus::Paid => "paid",
+            EpochStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Backoff between attempts to send the same winner or epoch.
+#[cw_serde]
+#[derive(Eq)]
+pub struct RetryPolicy {
+    /// Seconds to wait after the first attempt.
+    pub base_delay: u64,
+    /// Factor the wait grows by with each further attempt.
+    pub multiplier: Decimal,
+    /// Upper bound of the wait, in seconds.
+    pub max_delay: u64,
+    /// Attempts after which the key is failed until the owner resets it.
+    pub max_attempts: u32,
+}
+
+impl RetryPolicy {
+    /// Seconds to wait after the `attempts`-th attempt.
+    pub fn delay(&self, attempts: u32) -> u64 {
+        let factor = self
+            .multiplier
+            .checked_pow(attempts.saturating_sub(1))
+            .unwrap_or(Decimal::MAX);
+        let delay = Uint128::from(self.base_delay)
+            .checked_mul_floor(factor)
+            .unwrap_or(Uint128::MAX);
+        u64::try_from(delay.u128())
+            .unwrap_or(u64::MAX)
+            .min(self.max_delay)
+    }
+}
+
+/// Action whose sends are gated by a retry policy.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum RetryAction {
+    SetWinner,
+    SetActiveEpoch,
+}
+
+impl RetryAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetryAction::SetWinner => "set_winner",
+            RetryAction::SetActiveEpoch => "set_active_epoch",
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum RetryStatus {
+    /// May be sent now.
+    Ready,
+    /// Sent recently; the next attempt is allowed after `next_attempt_at`.
+    Waiting,
+    /// Out of attempts; needs `ResetRetry` from the owner.
+    Failed,
+}
+
+/// EVM chain running a competition Vyper contract.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Chain {
+    Eth,
+    Arb,
+}
+
+impl Chain {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Chain::Eth => "eth",
+            Chain::Arb => "arb",
+        }
+    }
+}
+
+/// Where a chain's competition contract lives, for bridging prize funds to it.
+#[cw_serde]
+pub struct ChainConfig {
+    /// Skyway chain reference id, e.g. `arbitrum-main`.
+    pub chain_reference_id: String,
+    /// EVM address of the competition Vyper contract.
+    pub competition_contract: String,
+}
+
+/// Protocol fee taken from an epoch's prize on a chain and paid to the treasury in its winner
+/// list.
+#[cw_serde]
+pub struct FeeConfig {
+    /// Share of the prize in basis points, at most 10000.
+    pub fee_bps: u16,
+    /// EVM address of the treasury.
+    pub treasury: String,
+}
+
+/// How `StartNextEpoch` generates a chain's epochs. Times are in seconds.
+#[cw_serde]
+pub struct EpochTemplate {
+    /// Length of each epoch, `competition_end - competition_start`.
+    pub duration: u64,
+    /// Time between the end of an epoch and the start of the next.
+    pub gap: u64,
+    pub prize_amount: Uint256,
+    /// Start of the first epoch; later epochs follow every `duration + gap`.
+    pub start_anchor: u64,
+}
+
+/// Tournament over a set of epochs with a prize on top of theirs. Times are in seconds.
+#[cw_serde]
+#[derive(Eq)]
+pub struct SeasonInfo {
+    pub start: u64,
+    pub end: u64,
+    /// Epochs whose winners score points, read from `arb`, or from `eth` for an epoch only run
+    /// there.
+    pub epochs: Vec<Uint256>,
+    /// Points for each epoch rank, first place first; lower ranks score nothing.
+    pub points_per_rank: Vec<u64>,
+    /// Season prize, split between players in proportion to their points.
+    pub prize_amount: Uint256,
+}
+
+/// Leaderboard sort key, highest first.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum LeaderboardOrder {
+    TotalWon,
+    Wins,
+}
+
+/// JSON-typed ABI argument, converted into an `ethabi::Token` against the target signature.
+#[cw_serde]
+#[derive(Eq)]
+pub enum AbiValue {
+    Address(String),
+    Uint(Uint256),
+    Int(Int256),
+    Bool(bool),
+    String(String),
+    Bytes(Binary),
+    FixedBytes(Binary),
+    /// Dynamic or fixed-size array.
+    Array(Vec<AbiValue>),
+    Tuple(Vec<AbiValue>),
+}
+
+/// Role granted by the owner to other addresses.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum Role {
+    /// Reports the outcome of dispatched EVM calls.
+    Relayer,
+    /// Resends earlier dispatches.
+    Operator,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Relayer => "relayer",
+            Role::Operator => "operator",
+        }
+    }
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
-    SetEthPaloma {},
-    UpdateEthCompass { new_compass: String },
-    SetWinnerList { winner_infos: Vec<WinnerInfo> },
-    SetArbPaloma {},
-    UpdateArbCompass { new_compass: String },
-    SetActiveEpoch { epoch_info: EpochInfo },
+    SetEthPaloma {
+        dry_run: Option<bool>,
+    },
+    UpdateEthCompass {
+        new_compass: String,
+        dry_run: Option<bool>,
+    },
+    /// Owner call, or a permissionless relay when `signature` is a valid attestor signature.
+    SetWinnerList {
+        /// Closed epoch the winners are for; the epoch of each chain paid on is settled.
+        epoch_id: Uint256,
+        winner_infos: Vec<WinnerInfo>,
+        signature: Option<Binary>,
+        dry_run: Option<bool>,
+    },
+    SetArbPaloma {
+        dry_run: Option<bool>,
+    },
+    UpdateArbCompass {
+        new_compass: String,
+        dry_run: Option<bool>,
+    },
+    /// Owner call, or a permissionless relay when `signature` is a valid attestor signature.
+    SetActiveEpoch {
+        epoch_info: EpochInfo,
+        signature: Option<Binary>,
+        dry_run: Option<bool>,
+    },
+    /// `SetActiveEpoch` on each of `chains`. Chains still waiting out their retry delay are
+    /// skipped without blocking the others.
+    SetActiveEpochAll {
+        epoch_info: EpochInfo,
+        chains: Vec<Chain>,
+        signature: Option<Binary>,
+        dry_run: Option<bool>,
+    },
+    BeginWinnerList {
+        epoch_id: Uint256,
+    },
+    AppendWinners {
+        epoch_id: Uint256,
+        winners: Vec<WinnerInfo>,
+    },
+    FinalizeWinnerList {
+        epoch_id: Uint256,
+        expected_count: u32,
+        expected_total: Uint256,
+        dry_run: Option<bool>,
+    },
+    CancelWinnerList {
+        epoch_id: Uint256,
+    },
+    UpdateApprovalConfig {
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl: u64,
+    },
+    ProposeWinnerList {
+        epoch_id: Uint256,
+        winner_infos: Vec<WinnerInfo>,
+    },
+    ApproveWinnerList {
+        proposal_id: u64,
+    },
+    RejectWinnerList {
+        proposal_id: u64,
+    },
+    AddAttestor {
+        pubkey: Binary,
+    },
+    RemoveAttestor {
+        pubkey: Binary,
+    },
+    /// Call an allowlisted function, e.g. `set_entry_fee(uint256)`, on a competition contract.
+    CallTarget {
+        chain: Chain,
+        function: String,
+        args: Vec<AbiValue>,
+        dry_run: Option<bool>,
+    },
+    AllowCall {
+        chain: Chain,
+        function: String,
+    },
+    DisallowCall {
+        chain: Chain,
+        function: String,
+    },
+    /// Register the JSON ABI used to encode calls to a chain's competition contract.
+    SetTargetAbi {
+        chain: Chain,
+        abi: String,
+    },
+    /// Go back to the built-in function definitions for a chain.
+    ClearTargetAbi {
+        chain: Chain,
+    },
+    GrantRole {
+        role: Role,
+        address: String,
+    },
+    RevokeRole {
+        role: Role,
+        address: String,
+    },
+    /// Relayer report of the EVM transaction that executed a dispatch.
+    ReportExecution {
+        dispatch_id: u64,
+        evm_tx_hash: String,
+        success: bool,
+        error: Option<String>,
+    },
+    /// Re-emit the job id and payload of an earlier dispatch.
+    Resend {
+        dispatch_id: u64,
+        dry_run: Option<bool>,
+    },
+    /// Set the retry policy of an action, or the default one when `action` is `None`.
+    UpdateRetryPolicy {
+        action: Option<RetryAction>,
+        retry_policy: RetryPolicy,
+    },
+    SetRollbackOnSubmitFailure {
+        enabled: bool,
+    },
+    SetPalomaMsgVersion {
+        version: PalomaMsgVersion,
+    },
+    /// Set the Paloma job a chain's messages are sent to. An empty job id leaves the chain
+    /// unconfigured.
+    SetJobId {
+        chain: Chain,
+        job_id: String,
+    },
+    SetChainConfig {
+        chain: Chain,
+        chain_reference_id: String,
+        competition_contract: String,
+    },
+    /// Set the protocol fee of a chain. Only the owner can call this, and once approvals are
+    /// enabled it creates a proposal.
+    SetFeeConfig {
+        chain: Chain,
+        fee_bps: u16,
+        treasury: String,
+    },
+    SetEpochTemplate {
+        chain: Chain,
+        template: EpochTemplate,
+    },
+    /// Generate the chain's next epoch from its template. It is activated right away if its time
+    /// slot has begun, otherwise queued for `Tick`.
+    StartNextEpoch {
+        chain: Chain,
+        dry_run: Option<bool>,
+    },
+    /// Bridge the native coin sent along to the chain's competition contract as epoch prize money.
+    FundPrizePool {
+        chain: Chain,
+        epoch_id: Uint256,
+    },
+    /// CW20 prize funding, with a `Cw20HookMsg` as the inner message.
+    Receive(Cw20ReceiveMsg),
+    /// Queue an upcoming epoch for `Tick` to activate once its `competition_start` has passed.
+    /// `chain` defaults to `arb`.
+    ScheduleEpoch {
+        epoch_info: EpochInfo,
+        chain: Option<Chain>,
+    },
+    /// Close an active epoch before its `competition_end`.
+    CloseEpoch {
+        chain: Chain,
+        epoch_id: Uint256,
+    },
+    /// Raise the prize or extend the end of an active epoch, and run `amend_epoch` on the chain's
+    /// competition contract. `chain` defaults to `arb`.
+    AmendEpoch {
+        epoch_id: Uint256,
+        prize_amount: Option<Uint256>,
+        competition_end: Option<Uint256>,
+        chain: Option<Chain>,
+        dry_run: Option<bool>,
+    },
+    /// Mark an epoch cancelled, so its sponsors can take their CW20 contributions back, and
+    /// run `cancel_epoch` on the chain's competition contract if the epoch reached it.
+    CancelEpoch {
+        chain: Chain,
+        epoch_id: Uint256,
+        reason: String,
+        dry_run: Option<bool>,
+    },
+    /// Return the caller's CW20 contribution of `token` to a cancelled epoch.
+    RefundSponsorship {
+        chain: Chain,
+        epoch_id: Uint256,
+        token: String,
+    },
+    /// Send every CW20 contribution of `token` to a paid epoch to `recipient`.
+    SweepSponsorships {
+        chain: Chain,
+        epoch_id: Uint256,
+        token: String,
+        recipient: String,
+    },
+    /// Clear the attempt counter of a winner address or epoch id. `chain` selects the chain of
+    /// the key and defaults to `eth` for winners and `arb` for epochs. Winner attempts are
+    /// counted per payout, so `set_winner` keys also need the `epoch_id`.
+    ResetRetry {
+        action: RetryAction,
+        key: String,
+        chain: Option<Chain>,
+        epoch_id: Option<Uint256>,
+    },
+    /// Activate every queued epoch whose `competition_start` has passed. Anyone can call this.
+    Tick {},
+    /// Create or replace a season that has not been settled yet.
+    SetSeason {
+        season_id: u64,
+        season_info: SeasonInfo,
+    },
+    /// Once the season has ended and its epochs are settled, run `set_winner_list` paying out the
+    /// season prize by points.
+    SettleSeason {
+        season_id: u64,
+        dry_run: Option<bool>,
+    },
+}
+
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Contribute the received tokens to an epoch's prize. `chain` defaults to `arb`.
+    FundEpoch {
+        epoch_id: Uint256,
+        chain: Option<Chain>,
+    },
+}
+
+impl ExecuteMsg {
+    /// Whether the message only validates and encodes, returning the `DispatchData` it would
+    /// produce without writing storage or sending messages.
+    pub fn dry_run(&self) -> bool {
+        match self {
+            ExecuteMsg::SetEthPaloma { dry_run }
+            | ExecuteMsg::UpdateEthCompass { dry_run, .. }
+            | ExecuteMsg::SetWinnerList { dry_run, .. }
+            | ExecuteMsg::SetArbPaloma { dry_run }
+            | ExecuteMsg::UpdateArbCompass { dry_run, .. }
+            | ExecuteMsg::SetActiveEpoch { dry_run, .. }
+            | ExecuteMsg::SetActiveEpochAll { dry_run, .. }
+            | ExecuteMsg::FinalizeWinnerList { dry_run, .. }
+            | ExecuteMsg::CallTarget { dry_run, .. }
+            | ExecuteMsg::Resend { dry_run, .. }
+            | ExecuteMsg::StartNextEpoch { dry_run, .. }
+            | ExecuteMsg::AmendEpoch { dry_run, .. }
+            | ExecuteMsg::CancelEpoch { dry_run, .. }
+            | ExecuteMsg::SettleSeason { dry_run, .. } => dry_run.unwrap_or(false),
+            _ => false,
+        }
+    }
 }
 
 #[cw_serde]
@@ -42,9 +506,46 @@ pub struct Metadata {
     pub signers: Vec<String>,
 }
 
-/// Message struct for cross-chain calls.
+/// Message for cross-chain calls, in the schema of the deployment's `PalomaMsgVersion`.
 #[cw_serde]
-pub struct PalomaMsg {
+#[serde(untagged)]
+pub enum PalomaMsg {
+    V1(PalomaMsgV1),
+    V2(PalomaMsgV2),
+}
+
+impl PalomaMsg {
+    /// Job run message in the schema of `version`.
+    pub fn execute_job(
+        version: &PalomaMsgVersion,
+        job_id: String,
+        payload: Binary,
+        metadata: Metadata,
+    ) -> Self {
+        match version {
+            PalomaMsgVersion::V1 => PalomaMsg::V1(PalomaMsgV1 {
+                job_id,
+                payload,
+                metadata,
+            }),
+            PalomaMsgVersion::V2 {
+                assign_count,
+                gas_estimate,
+            } => PalomaMsg::V2(PalomaMsgV2::SchedulerMsg {
+                execute_job: ExecuteJob {
+                    job_id,
+                    payload,
+                    assign_count: *assign_count,
+                    gas_estimate: *gas_estimate,
+                },
+            }),
+        }
+    }
+}
+
+/// Job run in the `{ job_id, payload, metadata }` shape of older Paloma chains.
+#[cw_serde]
+pub struct PalomaMsgV1 {
     /// The ID of the paloma scheduled job to run.
     pub job_id: String,
     /// The payload, ABI encoded for the target chain.
@@ -53,6 +554,76 @@ pub struct PalomaMsg {
     pub metadata: Metadata,
 }
 
+#[cw_serde]
+pub enum PalomaMsgV2 {
+    /// Run a scheduled job.
+    SchedulerMsg { execute_job: ExecuteJob },
+    /// Skyway bridge transfer or cancellation.
+    SkywayMsg {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        send_tx: Option<SendTx>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cancel_tx: Option<CancelTx>,
+    },
+    TokenFactoryMsg {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        create_denom: Option<CreateDenomMsg>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mint_tokens: Option<MintMsg>,
+    },
+}
+
+#[cw_serde]
+pub struct ExecuteJob {
+    pub job_id: String,
+    /// The payload, ABI encoded for the target chain.
+    pub payload: Binary,
+    /// Number of validators to assign the job to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assign_count: Option<u32>,
+    /// Gas limit estimate for the EVM call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_estimate: Option<u64>,
+}
+
+#[cw_serde]
+pub struct SendTx {
+    pub remote_chain_destination_address: String,
+    pub amount: String,
+    pub chain_reference_id: String,
+}
+
+#[cw_serde]
+pub struct CancelTx {
+    pub transaction_id: u64,
+}
+
+#[cw_serde]
+pub struct CreateDenomMsg {
+    pub subdenom: String,
+}
+
+#[cw_serde]
+pub struct MintMsg {
+    pub denom: String,
+    pub amount: Uint128,
+    pub mint_to_address: String,
+}
+
+/// `PalomaMsg` schema understood by the Paloma chain this contract runs on.
+#[cw_serde]
+#[derive(Default, Eq)]
+pub enum PalomaMsgVersion {
+    /// `{ job_id, payload, metadata }`.
+    #[default]
+    V1,
+    /// `{ "scheduler_msg": { "execute_job": ... } }`.
+    V2 {
+        assign_count: Option<u32>,
+        gas_estimate: Option<u64>,
+    },
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
@@ -61,6 +632,74 @@ pub enum QueryMsg {
     GetEthJobId {},
     #[returns(GetJobIdResponse)]
     GetArbJobId {},
+    #[returns(StagedWinnerListResponse)]
+    GetStagedWinnerList { epoch_id: Uint256 },
+    #[returns(ApprovalConfigResponse)]
+    GetApprovalConfig {},
+    #[returns(WinnerListProposalResponse)]
+    GetWinnerListProposal { proposal_id: u64 },
+    #[returns(AttestorsResponse)]
+    GetAttestors {},
+    #[returns(AllowedCallsResponse)]
+    GetAllowedCalls { chain: Chain },
+    #[returns(TargetAbiResponse)]
+    GetTargetAbi { chain: Chain },
+    /// Decode the calldata of a `PalomaMsg` payload sent to a chain.
+    #[returns(DecodePayloadResponse)]
+    DecodePayload { chain: Chain, payload: Binary },
+    #[returns(DispatchResponse)]
+    GetDispatch { dispatch_id: u64 },
+    #[returns(RoleMembersResponse)]
+    GetRoleMembers { role: Role },
+    #[returns(PalomaMsgVersion)]
+    GetPalomaMsgVersion {},
+    #[returns(ChainConfig)]
+    GetChainConfig { chain: Chain },
+    #[returns(EpochTemplate)]
+    GetEpochTemplate { chain: Chain },
+    #[returns(FeeConfig)]
+    GetFeeConfig { chain: Chain },
+    /// Protocol fees of dispatches reported successful so far.
+    #[returns(FeeTotalsResponse)]
+    GetFeeTotals {},
+    #[returns(PrizePoolResponse)]
+    GetPrizePool { chain: Chain, epoch_id: Uint256 },
+    #[returns(EpochResponse)]
+    GetEpoch { chain: Chain, epoch_id: Uint256 },
+    /// Scheduled epochs waiting for `Tick`, in activation order.
+    #[returns(EpochQueueResponse)]
+    GetEpochQueue { chain: Chain },
+    /// CW20 contributions to an epoch, optionally of a single sponsor.
+    #[returns(SponsorBalancesResponse)]
+    GetSponsorBalances {
+        chain: Chain,
+        epoch_id: Uint256,
+        sponsor: Option<String>,
+    },
+    /// Players ranked over winner lists sent so far, ties in descending address order. `chain`
+    /// limits the ranking to wins paid on that chain.
+    #[returns(LeaderboardResponse)]
+    Leaderboard {
+        chain: Option<Chain>,
+        order_by: LeaderboardOrder,
+        limit: Option<u32>,
+        /// Address of the last player of the previous page.
+        start_after: Option<String>,
+    },
+    #[returns(PlayerStatsResponse)]
+    PlayerStats { address: String },
+    /// Season details with its standings so far.
+    #[returns(SeasonResponse)]
+    GetSeason { season_id: u64 },
+    #[returns(RetryPolicy)]
+    GetRetryPolicy { action: Option<RetryAction> },
+    #[returns(RetryStateResponse)]
+    GetRetryState {
+        action: RetryAction,
+        key: String,
+        chain: Option<Chain>,
+        epoch_id: Option<Uint256>,
+    },
 }
 
 // We define a custom struct for each query response
@@ -69,4 +708,294 @@ pub struct GetJobIdResponse {
     pub job_id: String,
 }
 
+#[cw_serde]
+pub struct StagedWinnerListResponse {
+    pub epoch_id: Uint256,
+    /// Number of staged winners, or `None` when no upload is in progress.
+    pub count: Option<u32>,
+    /// Sum of staged `claimable_amount`s, or `None` when no upload is in progress.
+    pub total: Option<Uint256>,
+}
+
+/// What a proposal does once approved.
+#[cw_serde]
+#[derive(Eq, Default)]
+pub enum ProposalAction {
+    /// Send the proposal's `winner_infos` for its `epoch_id`.
+    #[default]
+    SetWinnerList,
+    UpdateApprovalConfig {
+        approvers: Vec<String>,
+        threshold: u32,
+        proposal_ttl: u64,
+    },
+    CallTarget {
+        chain: Chain,
+        function: String,
+        args: Vec<AbiValue>,
+    },
+    AddAttestor {
+        pubkey: Binary,
+    },
+    /// Pay the proposal's `winner_infos` as the season prize.
+    SettleSeason {
+        season_id: u64,
+    },
+    SetFeeConfig {
+        chain: Chain,
+        fee_bps: u16,
+        treasury: String,
+    },
+}
+
+impl ProposalAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalAction::SetWinnerList => "set_winner_list",
+            ProposalAction::UpdateApprovalConfig { .. } => "update_approval_config",
+            ProposalAction::CallTarget { .. } => "call_target",
+            ProposalAction::AddAttestor { .. } => "add_attestor",
+            ProposalAction::SettleSeason { .. } => "settle_season",
+            ProposalAction::SetFeeConfig { .. } => "set_fee_config",
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(Eq)]
+pub enum ProposalStatus {
+    Pending,
+    Executed,
+    Rejected,
+    Expired,
+}
+
+#[cw_serde]
+pub struct ApprovalConfigResponse {
+    pub approvers: Vec<String>,
+    pub threshold: u32,
+    pub proposal_ttl: u64,
+}
+
+#[cw_serde]
+pub struct WinnerListProposalResponse {
+    pub proposal_id: u64,
+    pub action: ProposalAction,
+    /// Zero unless `action` is `set_winner_list`.
+    pub epoch_id: Uint256,
+    /// The proposed winners for `set_winner_list`, the computed payouts for `settle_season`, and
+    /// empty for other actions.
+    pub winner_infos: Vec<WinnerInfo>,
+    /// SHA-256 of the JSON-encoded `(epoch_id, winner_infos)` pair, of `(action, winner_infos)`
+    /// for `settle_season`, or of any other action.
+    pub hash: Binary,
+    pub approvals: Vec<String>,
+    pub rejections: Vec<String>,
+    pub expires_at: Timestamp,
+    pub status: ProposalStatus,
+}
+
+#[cw_serde]
+pub struct AttestorsResponse {
+    /// secp256k1 public keys, SEC1 encoded.
+    pub pubkeys: Vec<Binary>,
+}
+
+#[cw_serde]
+pub struct AllowedCallsResponse {
+    /// Canonical function signatures allowed for `CallTarget`.
+    pub functions: Vec<String>,
+}
+
+#[cw_serde]
+pub struct TargetAbiResponse {
+    /// Registered JSON ABI, `None` when the built-in definitions are used.
+    pub abi: Option<String>,
+    /// Signatures of the functions calls are encoded against.
+    pub functions: Vec<String>,
+}
+
+#[cw_serde]
+pub struct DecodePayloadResponse {
+    /// Signature of the function matching the payload selector.
+    pub function: String,
+    pub args: Vec<AbiValue>,
+    /// Set when the payload is a `set_winner_list` call.
+    pub winner_infos: Option<Vec<WinnerInfo>>,
+    /// Set when the payload is a `set_active_epoch` call.
+    pub epoch_info: Option<EpochInfo>,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+pub enum DispatchStatus {
+    Pending,
+    Confirmed,
+    Failed,
+}
+
+#[cw_serde]
+pub struct DispatchResponse {
+    pub dispatch_id: u64,
+    pub chain: Chain,
+    pub action: String,
+    pub job_id: String,
+    pub status: DispatchStatus,
+    pub created_at: Timestamp,
+    pub last_sent_at: Timestamp,
+    pub resend_count: u32,
+    pub payload: Binary,
+    pub submitted: Option<bool>,
+    pub submit_error: Option<String>,
+    pub evm_tx_hash: Option<String>,
+    pub error: Option<String>,
+    pub reported_at: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct RoleMembersResponse {
+    pub members: Vec<String>,
+}
+
+#[cw_serde]
+pub struct RetryStateResponse {
+    pub attempts: u32,
+    pub last_attempt_at: Option<Timestamp>,
+    pub next_attempt_at: Option<Timestamp>,
+    pub status: RetryStatus,
+}
+
+#[cw_serde]
+pub struct PrizePoolResponse {
+    pub chain: Chain,
+    pub epoch_id: Uint256,
+    /// `prize_amount` of the epoch activated on the chain, `None` before activation.
+    pub committed: Option<Uint256>,
+    /// Coins bridged for the epoch so far.
+    pub funded: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct EpochResponse {
+    pub chain: Chain,
+    pub epoch_id: Uint256,
+    /// `None` for an epoch cancelled before it was scheduled.
+    pub epoch_info: Option<EpochInfo>,
+    pub status: EpochStatus,
+    /// Time of the latest stored status change.
+    pub updated_at: Timestamp,
+    /// Why the owner cancelled the epoch.
+    pub cancel_reason: Option<String>,
+    /// `AmendEpoch` changes, oldest first.
+    pub amendments: Vec<EpochAmendment>,
+    /// `set_winner_list` dispatches paying the epoch's winners.
+    pub payout_dispatches: Vec<u64>,
+    /// Winners in rank order, as first sent by a winner list.
+    pub winners: Vec<WinnerInfo>,
+}
+
+#[cw_serde]
+#[derive(Eq)]
+pub struct EpochAmendment {
+    pub previous_prize_amount: Uint256,
+    pub prize_amount: Uint256,
+    pub previous_competition_end: Uint256,
+    pub competition_end: Uint256,
+    pub amended_at: Timestamp,
+    /// Dispatch of the `amend_epoch` call.
+    pub dispatch_id: u64,
+}
+
+/// Wins of a player sent in winner lists, over every chain or on one.
+#[cw_serde]
+pub struct PlayerStats {
+    pub address: String,
+    /// `None` for the totals over every chain.
+    pub chain: Option<Chain>,
+    pub total_won: Uint256,
+    pub wins: u64,
+    /// Best position in an epoch's winner list, 1 for first place.
+    pub best_rank: u32,
+    pub last_win_epoch: Uint256,
+}
+
+#[cw_serde]
+pub struct LeaderboardResponse {
+    pub players: Vec<PlayerStats>,
+}
+
+#[cw_serde]
+pub struct PlayerStatsResponse {
+    pub address: String,
+    /// `None` if the address never won.
+    pub overall: Option<PlayerStats>,
+    /// Stats per chain the player was paid on.
+    pub chains: Vec<PlayerStats>,
+}
+
+#[cw_serde]
+pub struct FeeTotalsResponse {
+    pub totals: Vec<FeeTotal>,
+}
+
+#[cw_serde]
+pub struct FeeTotal {
+    pub chain: Chain,
+    pub amount: Uint256,
+}
+
+#[cw_serde]
+pub struct SeasonResponse {
+    pub season_id: u64,
+    pub season_info: SeasonInfo,
+    /// Players by points, highest first.
+    pub standings: Vec<SeasonStanding>,
+    pub settled_at: Option<Timestamp>,
+    /// `set_winner_list` dispatches paying the season prize.
+    pub payout_dispatches: Vec<u64>,
+}
+
+#[cw_serde]
+pub struct SeasonStanding {
+    pub address: String,
+    pub points: u64,
+    /// Chain of the player's latest scoring win, where the season prize is paid.
+    pub chain: Chain,
+}
+
+#[cw_serde]
+pub struct EpochQueueResponse {
+    pub chain: Chain,
+    pub epochs: Vec<EpochInfo>,
+}
+
+#[cw_serde]
+pub struct SponsorBalance {
+    pub sponsor: String,
+    /// CW20 token contract.
+    pub token: String,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct SponsorBalancesResponse {
+    pub cancelled: bool,
+    pub balances: Vec<SponsorBalance>,
+}
+
+/// `Response::data` of every message that sends a `PalomaMsg`.
+#[cw_serde]
+pub struct DispatchData {
+    pub dispatch_id: u64,
+    pub chain: Chain,
+    pub action: String,
+    pub job_id: String,
+    /// SHA-256 of the payload.
+    pub payload_hash: Binary,
+    /// Winner addresses or epoch ids carried by the payload.
+    pub dispatched: Vec<String>,
+    /// Winner addresses left out because they are waiting out their retry delay or out of attempts.
+    pub skipped: Vec<String>,
+}
+
 impl CustomMsg for PalomaMsg {}
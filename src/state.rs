@@ -1,12 +1,68 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::msg::Metadata;
-use cosmwasm_std::{Addr, Timestamp};
-use cw_storage_plus::{Item, Map};
+use crate::msg::{
+    Chain, ChainConfig, DispatchStatus, EpochAmendment, EpochInfo, EpochStatus, EpochTemplate,
+    FeeConfig, Metadata, PalomaMsgVersion, PlayerStats, ProposalAction, ProposalStatus,
+    RetryPolicy, RetryStatus, SeasonInfo, WinnerInfo,
+};
+use cosmwasm_std::{Addr, Binary, Empty, Timestamp, Uint128, Uint256};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct State {
+    /// Retry policy of actions without their own entry in `RETRY_POLICIES`.
+    pub retry_policy: RetryPolicy,
+    /// Undo the retry attempts of a dispatch the Paloma module rejects.
+    #[serde(default)]
+    pub rollback_on_submit_failure: bool,
+    #[serde(default)]
+    pub paloma_msg_version: PalomaMsgVersion,
+    pub job_eth_id: String,
+    pub job_arb_id: String,
+    pub owner: Addr,
+    pub metadata: Metadata,
+}
+
+impl State {
+    pub fn job_id(&self, chain: Chain) -> &str {
+        match chain {
+            Chain::Eth => &self.job_eth_id,
+            Chain::Arb => &self.job_arb_id,
+        }
+    }
+}
+
+/// Attempts to send a winner address or epoch id for an action.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RetryState {
+    /// Time of the latest attempt.
+    pub timestamp: Timestamp,
+    /// Attempts since the key was last confirmed or reset.
+    pub attempts: u32,
+    /// Set when the latest attempt was reported failed, so it can be retried immediately.
+    pub reopened: bool,
+}
+
+impl RetryState {
+    pub fn next_attempt_at(&self, policy: &RetryPolicy) -> Timestamp {
+        self.timestamp.plus_seconds(policy.delay(self.attempts))
+    }
+
+    pub fn status(&self, policy: &RetryPolicy, now: Timestamp) -> RetryStatus {
+        if self.attempts >= policy.max_attempts {
+            RetryStatus::Failed
+        } else if self.reopened || self.next_attempt_at(policy).lt(&now) {
+            RetryStatus::Ready
+        } else {
+            RetryStatus::Waiting
+        }
+    }
+}
+
+/// `State` as stored before retry policies, with one delay for every action.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct LegacyState {
     pub retry_delay: u64,
     pub job_eth_id: String,
     pub job_arb_id: String,
@@ -14,5 +70,223 @@ pub struct State {
     pub metadata: Metadata,
 }
 
-pub const WITHDRAW_TIMESTAMP: Map<(String, String), Timestamp> = Map::new("withdraw_timestamp");
+pub const LEGACY_STATE: Item<LegacyState> = Item::new("state");
+pub const WITHDRAW_TIMESTAMP: Map<(String, String), RetryState> = Map::new("withdraw_timestamp");
+/// `WITHDRAW_TIMESTAMP` as stored before attempts were counted.
+pub const LEGACY_WITHDRAW_TIMESTAMP: Map<(String, String), Timestamp> =
+    Map::new("withdraw_timestamp");
+/// Per-action overrides of `State::retry_policy`.
+pub const RETRY_POLICIES: Map<&str, RetryPolicy> = Map::new("retry_policies");
 pub const STATE: Item<State> = Item::new("state");
+
+/// Progress of a winner list being uploaded across several transactions.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct WinnerListStaging {
+    pub count: u32,
+    pub total: Uint256,
+}
+
+pub const WINNER_LIST_STAGING: Map<String, WinnerListStaging> = Map::new("winner_list_staging");
+pub const STAGED_WINNERS: Map<(String, u32), WinnerInfo> = Map::new("staged_winners");
+
+/// Approvers that must sign off a winner list before it is dispatched. A zero threshold disables approvals.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct ApprovalConfig {
+    pub approvers: Vec<Addr>,
+    pub threshold: u32,
+    pub proposal_ttl: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct WinnerListProposal {
+    /// `SetWinnerList` proposals carry `epoch_id` and `winner_infos`; other actions leave them
+    /// empty.
+    #[serde(default)]
+    pub action: ProposalAction,
+    pub epoch_id: Uint256,
+    pub winner_infos: Vec<WinnerInfo>,
+    pub hash: Binary,
+    pub approvals: Vec<Addr>,
+    pub rejections: Vec<Addr>,
+    pub expires_at: Timestamp,
+    pub status: ProposalStatus,
+}
+
+pub const APPROVAL_CONFIG: Item<ApprovalConfig> = Item::new("approval_config");
+pub const PROPOSALS: Map<u64, WinnerListProposal> = Map::new("proposals");
+pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+
+/// secp256k1 public keys whose signatures make winner lists and epochs relayable by anyone.
+pub const ATTESTORS: Map<&[u8], Empty> = Map::new("attestors");
+/// Attestation hashes already relayed; each attested payload is accepted once.
+pub const USED_ATTESTATIONS: Map<&[u8], Empty> = Map::new("used_attestations");
+
+/// Canonical function signatures `CallTarget` may dispatch, keyed by chain.
+pub const CALL_ALLOWLIST: Map<(&str, &str), Empty> = Map::new("call_allowlist");
+
+/// JSON ABI of the competition contract, keyed by chain. Overrides the built-in function definitions.
+pub const TARGET_ABIS: Map<&str, String> = Map::new("target_abis");
+
+/// A `PalomaMsg` sent by this contract, tracked until a relayer reports its EVM execution.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Dispatch {
+    pub chain: Chain,
+    pub action: String,
+    pub job_id: String,
+    pub payload: Binary,
+    pub metadata: Metadata,
+    pub status: DispatchStatus,
+    pub created_at: Timestamp,
+    pub last_sent_at: Timestamp,
+    pub resend_count: u32,
+    /// `WITHDRAW_TIMESTAMP` keys claimed by this dispatch.
+    pub retry_keys: Vec<(String, String)>,
+    /// Epoch a `set_winner_list` dispatch settles, or a `set_active_epoch` or `amend_epoch`
+    /// dispatch starts or amends.
+    #[serde(default)]
+    pub epoch_id: Option<Uint256>,
+    /// Whether the Paloma module accepted the latest send, `None` until its reply.
+    #[serde(default)]
+    pub submitted: Option<bool>,
+    /// Error the Paloma module rejected the latest send with.
+    #[serde(default)]
+    pub submit_error: Option<String>,
+    /// Protocol fee appended to a `set_winner_list` payload.
+    #[serde(default)]
+    pub fee: Option<Uint256>,
+    pub evm_tx_hash: Option<String>,
+    pub error: Option<String>,
+    pub reported_at: Option<Timestamp>,
+}
+
+pub const DISPATCHES: Map<u64, Dispatch> = Map::new("dispatches");
+pub const DISPATCH_COUNT: Item<u64> = Item::new("dispatch_count");
+
+/// Role members, keyed by `(role, address)`.
+pub const ROLES: Map<(&str, &Addr), Empty> = Map::new("roles");
+
+pub const CHAIN_CONFIGS: Map<&str, ChainConfig> = Map::new("chain_configs");
+
+pub const EPOCH_TEMPLATES: Map<&str, EpochTemplate> = Map::new("epoch_templates");
+
+pub const FEE_CONFIGS: Map<&str, FeeConfig> = Map::new("fee_configs");
+
+/// Amount sent in a `set_winner_list` dispatch. It counts against the epoch prize unless the
+/// Paloma module rejected the dispatch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Claim {
+    pub amount: Uint256,
+    pub dispatch_id: u64,
+}
+
+/// Latest amount each winner was sent, keyed by `((chain, epoch_id), winner)`.
+pub const EPOCH_CLAIMS: Map<((&str, String), &str), Claim> = Map::new("epoch_claims");
+
+/// Protocol fee sent per `(chain, epoch_id)`, so a resent winner list does not pay it again.
+pub const EPOCH_FEES: Map<(&str, String), Claim> = Map::new("epoch_fees");
+
+/// Protocol fees of confirmed dispatches per chain.
+pub const FEE_TOTALS: Map<&str, Uint256> = Map::new("fee_totals");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Epoch {
+    /// `None` for an epoch cancelled before it was scheduled.
+    pub epoch_info: Option<EpochInfo>,
+    pub status: EpochStatus,
+    pub updated_at: Timestamp,
+    #[serde(default)]
+    pub cancel_reason: Option<String>,
+    #[serde(default)]
+    pub amendments: Vec<EpochAmendment>,
+    #[serde(default)]
+    pub payout_dispatches: Vec<u64>,
+    /// Winners in rank order, as first sent by a winner list.
+    #[serde(default)]
+    pub winners: Vec<WinnerInfo>,
+}
+
+impl Epoch {
+    /// Current status; an active epoch counts as closed once its `competition_end` has passed.
+    pub fn status_at(&self, now: Timestamp) -> EpochStatus {
+        match &self.epoch_info {
+            Some(epoch_info)
+                if self.status == EpochStatus::Active
+                    && epoch_info.competition_end <= Uint256::from(now.seconds()) =>
+            {
+                EpochStatus::Closed
+            }
+            _ => self.status,
+        }
+    }
+}
+
+/// Epoch lifecycle, keyed by `(chain, epoch_id)`.
+pub const EPOCHS: Map<(&str, String), Epoch> = Map::new("epochs");
+
+/// Scheduled epochs waiting for `Tick`, keyed by `(chain, big-endian epoch_id)` so they range in
+/// activation order.
+pub const EPOCH_QUEUE: Map<(&str, &[u8]), EpochInfo> = Map::new("epoch_queue");
+
+/// Most recently activated epoch per chain; scheduled epochs must come after it.
+pub const LATEST_EPOCHS: Map<&str, EpochInfo> = Map::new("latest_epochs");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Season {
+    pub season_info: SeasonInfo,
+    pub settled_at: Option<Timestamp>,
+    pub payout_dispatches: Vec<u64>,
+}
+
+pub const SEASONS: Map<u64, Season> = Map::new("seasons");
+
+/// `PLAYER_STATS` bucket of the totals over every chain; the others are chain names.
+pub const ALL_CHAINS: &str = "all";
+
+pub struct PlayerStatsIndexes<'a> {
+    /// `(bucket, big-endian total_won)`.
+    pub total_won: MultiIndex<'a, (String, Vec<u8>), PlayerStats, (String, String)>,
+    pub wins: MultiIndex<'a, (String, u64), PlayerStats, (String, String)>,
+}
+
+impl IndexList<PlayerStats> for PlayerStatsIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<PlayerStats>> + '_> {
+        let indexes: Vec<&dyn Index<PlayerStats>> = vec![&self.total_won, &self.wins];
+        Box::new(indexes.into_iter())
+    }
+}
+
+fn player_stats_bucket(stats: &PlayerStats) -> String {
+    stats
+        .chain
+        .map_or(ALL_CHAINS, |chain| chain.as_str())
+        .to_string()
+}
+
+/// Player stats keyed by `(bucket, address)`, indexed per bucket for the leaderboard.
+pub fn player_stats<'a>() -> IndexedMap<'a, (&'a str, &'a str), PlayerStats, PlayerStatsIndexes<'a>>
+{
+    let indexes = PlayerStatsIndexes {
+        total_won: MultiIndex::new(
+            |_, stats| {
+                (
+                    player_stats_bucket(stats),
+                    stats.total_won.to_be_bytes().to_vec(),
+                )
+            },
+            "player_stats",
+            "player_stats__total_won",
+        ),
+        wins: MultiIndex::new(
+            |_, stats| (player_stats_bucket(stats), stats.wins),
+            "player_stats",
+            "player_stats__wins",
+        ),
+    };
+    IndexedMap::new("player_stats", indexes)
+}
+
+/// Prize money bridged per `(chain, epoch_id, denom)`.
+pub const PRIZE_FUNDING: Map<(&str, String, &str), Uint128> = Map::new("prize_funding");
+
+/// CW20 prize contributions per `((chain, epoch_id), token, sponsor)`.
+pub const SPONSORSHIPS: Map<((&str, String), &Addr, &Addr), Uint128> = Map::new("sponsorships");
@@ -0,0 +1,155 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, EpochInfo, EpochResponse, ExecuteMsg, InstantiateMsg, PalomaMsg, QueryMsg, RetryPolicy,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+/// Contract with an active `arb` epoch 1 ending in 100 seconds and a queued epoch 2 starting in
+/// 200.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    for msg in [
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: epoch_info(1, now - 10, now + 100),
+            signature: None,
+            dry_run: None,
+        },
+        ExecuteMsg::ScheduleEpoch {
+            epoch_info: epoch_info(2, now + 200, now + 300),
+            chain: None,
+        },
+    ] {
+        execute(deps.as_mut(), env.clone(), owner.clone(), msg).unwrap();
+    }
+    (deps, env)
+}
+
+fn epoch_info(epoch_id: u8, start: u64, end: u64) -> EpochInfo {
+    EpochInfo {
+        epoch_id: Uint256::from(epoch_id),
+        competition_start: Uint256::from(start),
+        competition_end: Uint256::from(end),
+        entry_cnt: Uint256::zero(),
+        prize_amount: Uint256::from(1_000u32),
+    }
+}
+
+fn amend(
+    deps: &mut Deps,
+    env: &Env,
+    prize_amount: Option<u32>,
+    competition_end: Option<u64>,
+    dry_run: Option<bool>,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::AmendEpoch {
+            epoch_id: Uint256::one(),
+            prize_amount: prize_amount.map(Uint256::from),
+            competition_end: competition_end.map(Uint256::from),
+            chain: None,
+            dry_run,
+        },
+    )
+}
+
+fn epoch(deps: &Deps, env: &Env) -> EpochResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn amendments_only_raise_the_prize_and_extend_the_end() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    for (prize_amount, competition_end) in [(None, None), (Some(1_000), None), (None, Some(now))] {
+        assert!(matches!(
+            amend(&mut deps, &env, prize_amount, competition_end, None),
+            Err(ContractError::InvalidAmendment {})
+        ));
+    }
+
+    amend(&mut deps, &env, Some(1_500), Some(now + 150), None).unwrap();
+    let epoch = epoch(&deps, &env);
+    let epoch_info = epoch.epoch_info.unwrap();
+    assert_eq!(epoch_info.prize_amount, Uint256::from(1_500u32));
+    assert_eq!(epoch_info.competition_end, Uint256::from(now + 150));
+    assert_eq!(epoch.amendments.len(), 1);
+    assert_eq!(
+        epoch.amendments[0].previous_competition_end,
+        Uint256::from(now + 100)
+    );
+}
+
+#[test]
+fn the_end_cannot_run_into_the_next_queued_epoch() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    assert!(matches!(
+        amend(&mut deps, &env, None, Some(now + 201), None),
+        Err(ContractError::EpochOverlap { epoch_id }) if epoch_id == "2"
+    ));
+    amend(&mut deps, &env, None, Some(now + 200), None).unwrap();
+}
+
+#[test]
+fn closed_epochs_cannot_be_amended() {
+    let (mut deps, mut env) = setup();
+    env.block.time = env.block.time.plus_seconds(101);
+    assert!(matches!(
+        amend(&mut deps, &env, Some(2_000), None, None),
+        Err(ContractError::EpochNotActive {})
+    ));
+}
+
+#[test]
+fn dry_run_keeps_the_epoch_as_it_is() {
+    let (mut deps, env) = setup();
+    let response = amend(&mut deps, &env, Some(2_000), None, Some(true)).unwrap();
+    assert!(response.messages.is_empty());
+    let epoch = epoch(&deps, &env);
+    assert_eq!(
+        epoch.epoch_info.unwrap().prize_amount,
+        Uint256::from(1_000u32)
+    );
+    assert!(epoch.amendments.is_empty());
+}
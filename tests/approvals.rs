@@ -0,0 +1,254 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    ApprovalConfigResponse, Chain, EpochInfo, EpochResponse, EpochStatus, ExecuteMsg,
+    InstantiateMsg, ProposalStatus, QueryMsg, RetryPolicy, WinnerInfo, WinnerListProposalResponse,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+/// Contract with a closed `arb` epoch 1 and approvers `alice`, `bob` and `carol`, two of whom
+/// must approve.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![WINNER.to_string()],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        update_approval_config(&["alice", "bob", "carol"], 2),
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn update_approval_config(approvers: &[&str], threshold: u32) -> ExecuteMsg {
+    ExecuteMsg::UpdateApprovalConfig {
+        approvers: approvers
+            .iter()
+            .map(|approver| approver.to_string())
+            .collect(),
+        threshold,
+        proposal_ttl: 3_600,
+    }
+}
+
+fn winner_infos() -> Vec<WinnerInfo> {
+    vec![WinnerInfo {
+        winner: WINNER.to_string(),
+        claimable_amount: Uint256::from(10u8),
+        chain: None,
+    }]
+}
+
+fn propose(deps: &mut Deps, env: &Env) -> u64 {
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::ProposeWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: winner_infos(),
+        },
+    )
+    .unwrap();
+    response
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "proposal_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap()
+}
+
+fn vote(deps: &mut Deps, env: &Env, voter: &str, msg: ExecuteMsg) -> Result<(), ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info(voter, &[]), msg).map(|_| ())
+}
+
+fn proposal(deps: &Deps, env: &Env, proposal_id: u64) -> WinnerListProposalResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetWinnerListProposal { proposal_id },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn epoch_status(deps: &Deps, env: &Env) -> EpochStatus {
+    let epoch: EpochResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    epoch.status
+}
+
+#[test]
+fn winner_list_needs_approval() {
+    let (mut deps, env) = setup();
+    let result = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: winner_infos(),
+            signature: None,
+            dry_run: None,
+        },
+    );
+    assert!(matches!(result, Err(ContractError::ApprovalRequired {})));
+}
+
+#[test]
+fn threshold_approvals_execute_the_proposal() {
+    let (mut deps, env) = setup();
+    let proposal_id = propose(&mut deps, &env);
+    let approve = || ExecuteMsg::ApproveWinnerList { proposal_id };
+
+    assert!(matches!(
+        vote(&mut deps, &env, "mallory", approve()),
+        Err(ContractError::Unauthorized {})
+    ));
+    vote(&mut deps, &env, "alice", approve()).unwrap();
+    assert!(matches!(
+        vote(&mut deps, &env, "alice", approve()),
+        Err(ContractError::AlreadyVoted {})
+    ));
+    assert_eq!(
+        proposal(&deps, &env, proposal_id).status,
+        ProposalStatus::Pending
+    );
+    assert_eq!(epoch_status(&deps, &env), EpochStatus::Closed);
+
+    vote(&mut deps, &env, "bob", approve()).unwrap();
+    let proposal = proposal(&deps, &env, proposal_id);
+    assert_eq!(proposal.status, ProposalStatus::Executed);
+    assert_eq!(proposal.approvals, vec!["alice", "bob"]);
+    assert_eq!(epoch_status(&deps, &env), EpochStatus::Settled);
+}
+
+#[test]
+fn rejections_close_the_proposal_once_the_threshold_is_out_of_reach() {
+    let (mut deps, env) = setup();
+    let proposal_id = propose(&mut deps, &env);
+    let reject = || ExecuteMsg::RejectWinnerList { proposal_id };
+
+    // Two of three approvers can still approve after one rejection.
+    vote(&mut deps, &env, "alice", reject()).unwrap();
+    assert_eq!(
+        proposal(&deps, &env, proposal_id).status,
+        ProposalStatus::Pending
+    );
+    vote(&mut deps, &env, "bob", reject()).unwrap();
+    assert_eq!(
+        proposal(&deps, &env, proposal_id).status,
+        ProposalStatus::Rejected
+    );
+    assert!(vote(
+        &mut deps,
+        &env,
+        "carol",
+        ExecuteMsg::ApproveWinnerList { proposal_id }
+    )
+    .is_err());
+    assert_eq!(epoch_status(&deps, &env), EpochStatus::Closed);
+}
+
+#[test]
+fn config_changes_need_the_current_approvers() {
+    let (mut deps, env) = setup();
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        update_approval_config(&["owner"], 0),
+    )
+    .unwrap();
+    let config: ApprovalConfigResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::GetApprovalConfig {}).unwrap())
+            .unwrap();
+    assert_eq!(config.threshold, 2);
+
+    let proposal_id: u64 = response
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "proposal_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+    assert_eq!(
+        proposal(&deps, &env, proposal_id).status,
+        ProposalStatus::Pending
+    );
+    for approver in ["alice", "carol"] {
+        vote(
+            &mut deps,
+            &env,
+            approver,
+            ExecuteMsg::ApproveWinnerList { proposal_id },
+        )
+        .unwrap();
+    }
+    let config: ApprovalConfigResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::GetApprovalConfig {}).unwrap())
+            .unwrap();
+    assert_eq!(config.threshold, 0);
+    assert_eq!(config.approvers, vec!["owner"]);
+}
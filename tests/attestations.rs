@@ -0,0 +1,216 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{Binary, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use ethabi::Token;
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate};
+use juice_bot_eth_predictor_cw::evm::parse_address;
+use juice_bot_eth_predictor_cw::msg::{
+    EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg, RetryPolicy, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use k256::ecdsa::{Signature, SigningKey};
+use sha2::{Digest, Sha256};
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn attestor() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32].into()).unwrap()
+}
+
+/// Contract with `attestor()` registered and a closed `arb` epoch 1.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    let pubkey = attestor()
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::AddAttestor {
+            pubkey: Binary(pubkey),
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    relay(
+        &mut deps,
+        &env,
+        set_active_epoch(epoch_info(now - 10, now + 10), &env),
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    (deps, env)
+}
+
+fn epoch_info(start: u64, end: u64) -> EpochInfo {
+    EpochInfo {
+        epoch_id: Uint256::one(),
+        competition_start: Uint256::from(start),
+        competition_end: Uint256::from(end),
+        entry_cnt: Uint256::one(),
+        prize_amount: Uint256::from(1_000u32),
+    }
+}
+
+fn uint(value: Uint256) -> Token {
+    Token::Uint(ethabi::Uint::from_big_endian(&value.to_be_bytes()))
+}
+
+/// Signature over `sha256(abi.encode(contract_address, action, payload))`.
+fn sign(env: &Env, action: &str, payload: Token) -> Binary {
+    let hash = Sha256::digest(ethabi::encode(&[
+        Token::String(env.contract.address.to_string()),
+        Token::String(action.to_string()),
+        payload,
+    ]));
+    let signature: Signature = attestor().sign_prehash(&hash).unwrap();
+    Binary(signature.to_bytes().to_vec())
+}
+
+fn set_active_epoch(epoch_info: EpochInfo, env: &Env) -> ExecuteMsg {
+    let payload = Token::Tuple(
+        [
+            epoch_info.epoch_id,
+            epoch_info.competition_start,
+            epoch_info.competition_end,
+            epoch_info.entry_cnt,
+            epoch_info.prize_amount,
+        ]
+        .into_iter()
+        .map(uint)
+        .collect(),
+    );
+    ExecuteMsg::SetActiveEpoch {
+        signature: Some(sign(env, "set_active_epoch", payload)),
+        epoch_info,
+        dry_run: None,
+    }
+}
+
+fn set_winner_list(amount: u32, env: &Env) -> ExecuteMsg {
+    let payload = Token::Tuple(vec![
+        uint(Uint256::one()),
+        Token::Array(vec![Token::Tuple(vec![
+            Token::Address(parse_address(WINNER).unwrap()),
+            uint(Uint256::from(amount)),
+            Token::String("eth".to_string()),
+        ])]),
+    ]);
+    ExecuteMsg::SetWinnerList {
+        epoch_id: Uint256::one(),
+        winner_infos: vec![WinnerInfo {
+            winner: WINNER.to_string(),
+            claimable_amount: Uint256::from(amount),
+            chain: None,
+        }],
+        signature: Some(sign(env, "set_winner_list", payload)),
+        dry_run: None,
+    }
+}
+
+/// Executes `msg` as an address that is neither the owner nor an approver.
+fn relay(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("keeper", &[]), msg)
+}
+
+#[test]
+fn signed_winner_lists_are_relayed_once() {
+    let (mut deps, env) = setup();
+    relay(&mut deps, &env, set_winner_list(10, &env)).unwrap();
+    assert!(matches!(
+        relay(&mut deps, &env, set_winner_list(10, &env)),
+        Err(ContractError::AttestationUsed {})
+    ));
+}
+
+#[test]
+fn signatures_must_match_the_payload() {
+    let (mut deps, env) = setup();
+    let ExecuteMsg::SetWinnerList {
+        signature,
+        epoch_id,
+        dry_run,
+        ..
+    } = set_winner_list(10, &env)
+    else {
+        unreachable!()
+    };
+    let tampered = ExecuteMsg::SetWinnerList {
+        epoch_id,
+        winner_infos: vec![WinnerInfo {
+            winner: WINNER.to_string(),
+            claimable_amount: Uint256::from(900u32),
+            chain: None,
+        }],
+        signature,
+        dry_run,
+    };
+    assert!(matches!(
+        relay(&mut deps, &env, tampered),
+        Err(ContractError::InvalidAttestation {})
+    ));
+    assert!(matches!(
+        relay(
+            &mut deps,
+            &env,
+            ExecuteMsg::SetWinnerList {
+                epoch_id: Uint256::one(),
+                winner_infos: vec![],
+                signature: None,
+                dry_run: None,
+            }
+        ),
+        Err(ContractError::Unauthorized {})
+    ));
+}
+
+#[test]
+fn signed_winner_lists_need_approval_once_enabled() {
+    let (mut deps, env) = setup();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::UpdateApprovalConfig {
+            approvers: vec!["alice".to_string()],
+            threshold: 1,
+            proposal_ttl: 3_600,
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        relay(&mut deps, &env, set_winner_list(10, &env)),
+        Err(ContractError::ApprovalRequired {})
+    ));
+}
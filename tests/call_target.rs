@@ -0,0 +1,173 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, Int256, MemoryStorage, OwnedDeps, Response, Uint256};
+use ethabi::ParamType;
+use juice_bot_eth_predictor_cw::abi::{canonical_signature, parse_signature, tokenize};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    AbiValue, AllowedCallsResponse, Chain, DispatchData, ExecuteMsg, InstantiateMsg, PalomaMsg,
+    QueryMsg, RetryPolicy,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn call_target(function: &str, args: Vec<AbiValue>) -> ExecuteMsg {
+    ExecuteMsg::CallTarget {
+        chain: Chain::Arb,
+        function: function.to_string(),
+        args,
+        dry_run: None,
+    }
+}
+
+#[test]
+fn signatures_are_canonicalized() {
+    assert_eq!(
+        canonical_signature("set_entry_fee( uint )").unwrap(),
+        "set_entry_fee(uint256)"
+    );
+    assert_eq!(
+        canonical_signature("set_limits(int,(address,uint[])[2])").unwrap(),
+        "set_limits(int256,(address,uint256[])[2])"
+    );
+    for signature in [
+        "set_entry_fee",
+        "(uint256)",
+        "set-fee(uint256)",
+        "set_fee(uint7)",
+        "set_fee(uint264)",
+        "set_fee(bytes33)",
+        "set_fee(fee)",
+        "set_fee(uint256",
+        "set_fee(uint256[0])",
+    ] {
+        assert!(
+            matches!(
+                parse_signature(signature),
+                Err(ContractError::InvalidSignature { .. })
+            ),
+            "{signature}"
+        );
+    }
+}
+
+#[test]
+fn values_must_fit_their_type() {
+    let uint = |value: u32| AbiValue::Uint(Uint256::from(value));
+    let int = |value: i32| AbiValue::Int(Int256::from(value));
+    assert!(tokenize(&uint(255), &ParamType::Uint(8)).is_ok());
+    assert!(tokenize(&uint(256), &ParamType::Uint(8)).is_err());
+    assert!(tokenize(&int(127), &ParamType::Int(8)).is_ok());
+    assert!(tokenize(&int(-128), &ParamType::Int(8)).is_ok());
+    assert!(tokenize(&int(128), &ParamType::Int(8)).is_err());
+    assert!(tokenize(&int(-129), &ParamType::Int(8)).is_err());
+    assert!(tokenize(&uint(1), &ParamType::Int(256)).is_err());
+    assert!(tokenize(
+        &AbiValue::Array(vec![uint(1), uint(2)]),
+        &ParamType::FixedArray(Box::new(ParamType::Uint(256)), 3)
+    )
+    .is_err());
+    assert!(tokenize(
+        &AbiValue::Address("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string()),
+        &ParamType::Address
+    )
+    .is_err());
+}
+
+#[test]
+fn only_allowlisted_functions_are_called() {
+    let (mut deps, env) = setup();
+    let args = vec![AbiValue::Uint(Uint256::from(5u8))];
+    assert!(matches!(
+        owner_execute(
+            &mut deps,
+            &env,
+            call_target("set_entry_fee(uint)", args.clone())
+        ),
+        Err(ContractError::CallNotAllowed { .. })
+    ));
+
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::AllowCall {
+            chain: Chain::Arb,
+            function: "set_entry_fee(uint)".to_string(),
+        },
+    )
+    .unwrap();
+    let allowed: AllowedCallsResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetAllowedCalls { chain: Chain::Arb },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(allowed.functions, vec!["set_entry_fee(uint256)"]);
+
+    let response =
+        owner_execute(&mut deps, &env, call_target("set_entry_fee(uint256)", args)).unwrap();
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(data.action, "call_target");
+    assert_eq!(data.job_id, "arb-job");
+    assert!(matches!(
+        owner_execute(
+            &mut deps,
+            &env,
+            call_target("set_entry_fee(uint256)", vec![])
+        ),
+        Err(ContractError::InvalidArguments { .. })
+    ));
+}
+
+#[test]
+fn managed_functions_cannot_be_allowlisted() {
+    let (mut deps, env) = setup();
+    assert!(matches!(
+        owner_execute(
+            &mut deps,
+            &env,
+            ExecuteMsg::AllowCall {
+                chain: Chain::Eth,
+                function: "set_winner_list((address,uint256)[])".to_string(),
+            },
+        ),
+        Err(ContractError::ManagedFunction { .. })
+    ));
+}
@@ -0,0 +1,215 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, EpochInfo, EpochResponse, EpochStatus, ExecuteMsg, InstantiateMsg,
+    PalomaMsg, QueryMsg, RetryPolicy, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn epoch_info(start: u64, end: u64) -> EpochInfo {
+    EpochInfo {
+        epoch_id: Uint256::one(),
+        competition_start: Uint256::from(start),
+        competition_end: Uint256::from(end),
+        entry_cnt: Uint256::zero(),
+        prize_amount: Uint256::from(1_000u32),
+    }
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn cancel(deps: &mut Deps, env: &Env) -> Result<Response<PalomaMsg>, ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::CancelEpoch {
+            chain: Chain::Arb,
+            epoch_id: Uint256::one(),
+            reason: "oracle outage".to_string(),
+            dry_run: None,
+        },
+    )
+}
+
+fn epoch(deps: &Deps, env: &Env) -> EpochResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn cancelling_an_active_epoch_runs_cancel_epoch_and_blocks_payouts() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: epoch_info(now - 10, now + 10),
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+
+    let response = cancel(&mut deps, &env).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(data.action, "cancel_epoch");
+    let epoch = epoch(&deps, &env);
+    assert_eq!(epoch.status, EpochStatus::Cancelled);
+    assert_eq!(epoch.cancel_reason.as_deref(), Some("oracle outage"));
+
+    let result = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: vec![WinnerInfo {
+                winner: WINNER.to_string(),
+                claimable_amount: Uint256::from(10u8),
+                chain: Some(Chain::Arb),
+            }],
+            signature: None,
+            dry_run: None,
+        },
+    );
+    assert!(matches!(result, Err(ContractError::EpochCancelled {})));
+}
+
+#[test]
+fn epochs_that_never_reached_the_chain_send_nothing() {
+    let (mut deps, env) = setup();
+    // Unknown to the contract.
+    let response = cancel(&mut deps, &env).unwrap();
+    assert!(response.messages.is_empty());
+    let epoch = epoch(&deps, &env);
+    assert_eq!(epoch.status, EpochStatus::Cancelled);
+    assert!(epoch.epoch_info.is_none());
+
+    // Only scheduled.
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::ScheduleEpoch {
+            epoch_info: epoch_info(now + 100, now + 200),
+            chain: None,
+        },
+    )
+    .unwrap();
+    assert!(cancel(&mut deps, &env).unwrap().messages.is_empty());
+}
+
+#[test]
+fn settled_epochs_cannot_be_cancelled() {
+    let (mut deps, mut env) = setup();
+    let now = env.block.time.seconds();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: epoch_info(now - 10, now + 10),
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: vec![],
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        cancel(&mut deps, &env),
+        Err(ContractError::InvalidEpochTransition { .. })
+    ));
+}
+
+#[test]
+fn dry_run_leaves_the_epoch_running() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: epoch_info(now - 10, now + 10),
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::CancelEpoch {
+            chain: Chain::Arb,
+            epoch_id: Uint256::one(),
+            reason: "oracle outage".to_string(),
+            dry_run: Some(true),
+        },
+    )
+    .unwrap();
+    assert!(response.messages.is_empty());
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(data.action, "cancel_epoch");
+    let epoch = epoch(&deps, &env);
+    assert_eq!(epoch.status, EpochStatus::Active);
+    assert!(epoch.cancel_reason.is_none());
+}
@@ -0,0 +1,178 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, Binary, Decimal, Env, MemoryStorage, OwnedDeps, Response, StdError, Uint256,
+};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    AbiValue, Chain, DecodePayloadResponse, DispatchData, DispatchResponse, EpochInfo, ExecuteMsg,
+    InstantiateMsg, PalomaMsg, QueryMsg, RetryPolicy, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+/// Calldata of the dispatch recorded by `response`.
+fn payload(deps: &Deps, env: &Env, response: Response<PalomaMsg>) -> Binary {
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    let dispatch: DispatchResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch {
+                dispatch_id: data.dispatch_id,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    dispatch.payload
+}
+
+fn decode(deps: &Deps, env: &Env, payload: Binary) -> Result<DecodePayloadResponse, StdError> {
+    query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::DecodePayload {
+            chain: Chain::Arb,
+            payload,
+        },
+    )
+    .map(|binary| from_json(binary).unwrap())
+}
+
+#[test]
+fn epoch_and_winner_payloads_are_decoded() {
+    let (mut deps, mut env) = setup();
+    let now = env.block.time.seconds();
+    let epoch_info = EpochInfo {
+        epoch_id: Uint256::one(),
+        competition_start: Uint256::from(now - 10),
+        competition_end: Uint256::from(now + 10),
+        entry_cnt: Uint256::from(3u8),
+        prize_amount: Uint256::from(1_000u32),
+    };
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: epoch_info.clone(),
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    let decoded = decode(&deps, &env, payload(&deps, &env, response)).unwrap();
+    assert_eq!(
+        decoded.function,
+        "set_active_epoch((uint256,uint256,uint256,uint256,uint256))"
+    );
+    assert_eq!(decoded.epoch_info, Some(epoch_info));
+    assert_eq!(decoded.winner_infos, None);
+
+    env.block.time = env.block.time.plus_seconds(20);
+    let winner_infos = vec![WinnerInfo {
+        winner: WINNER.to_string(),
+        claimable_amount: Uint256::from(100u8),
+        chain: Some(Chain::Arb),
+    }];
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: winner_infos.clone(),
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    let data: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    let dispatch: DispatchResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch {
+                dispatch_id: data[0].dispatch_id,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let decoded = decode(&deps, &env, dispatch.payload).unwrap();
+    assert_eq!(decoded.function, "set_winner_list((address,uint256)[])");
+    assert_eq!(decoded.winner_infos, Some(winner_infos));
+    assert_eq!(decoded.epoch_info, None);
+}
+
+#[test]
+fn allowlisted_calls_are_decoded() {
+    let (mut deps, env) = setup();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::AllowCall {
+            chain: Chain::Arb,
+            function: "set_entry_fee(uint256)".to_string(),
+        },
+    )
+    .unwrap();
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::CallTarget {
+            chain: Chain::Arb,
+            function: "set_entry_fee(uint256)".to_string(),
+            args: vec![AbiValue::Uint(Uint256::from(5u8))],
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    let decoded = decode(&deps, &env, payload(&deps, &env, response)).unwrap();
+    assert_eq!(decoded.function, "set_entry_fee(uint256)");
+    assert_eq!(decoded.args, vec![AbiValue::Uint(Uint256::from(5u8))]);
+}
+
+#[test]
+fn unknown_selectors_are_rejected() {
+    let (deps, env) = setup();
+    for payload in [vec![0xde, 0xad], vec![0xde, 0xad, 0xbe, 0xef]] {
+        let err = decode(&deps, &env, Binary::from(payload)).unwrap_err();
+        assert!(err.to_string().contains("UnknownSelector"), "{err}");
+    }
+}
@@ -0,0 +1,157 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Binary, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, DispatchResponse, EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg,
+    QueryMsg, RetryAction, RetryPolicy, RetryStateResponse, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+use sha2::{Digest, Sha256};
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const ALICE: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const BOB: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn set_active_epoch(env: &Env, dry_run: Option<bool>) -> ExecuteMsg {
+    let now = env.block.time.seconds();
+    ExecuteMsg::SetActiveEpoch {
+        epoch_info: EpochInfo {
+            epoch_id: Uint256::one(),
+            competition_start: Uint256::from(now - 10),
+            competition_end: Uint256::from(now + 10),
+            entry_cnt: Uint256::one(),
+            prize_amount: Uint256::from(1_000u32),
+        },
+        signature: None,
+        dry_run,
+    }
+}
+
+fn set_winner_list(winners: &[&str], dry_run: Option<bool>) -> ExecuteMsg {
+    ExecuteMsg::SetWinnerList {
+        epoch_id: Uint256::one(),
+        winner_infos: winners
+            .iter()
+            .map(|winner| WinnerInfo {
+                winner: winner.to_string(),
+                claimable_amount: Uint256::from(10u8),
+                chain: Some(Chain::Arb),
+            })
+            .collect(),
+        signature: None,
+        dry_run,
+    }
+}
+
+#[test]
+fn a_dry_run_returns_the_data_without_writing() {
+    let (mut deps, env) = setup();
+    let response = owner_execute(&mut deps, &env, set_active_epoch(&env, Some(true))).unwrap();
+    assert!(response.messages.is_empty());
+    let preview: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(preview.action, "set_active_epoch");
+    assert_eq!(preview.job_id, "arb-job");
+    assert_eq!(preview.dispatched, vec!["1"]);
+
+    assert!(query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::GetDispatch {
+            dispatch_id: preview.dispatch_id,
+        },
+    )
+    .is_err());
+    let retry: RetryStateResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRetryState {
+                action: RetryAction::SetActiveEpoch,
+                key: "1".to_string(),
+                chain: None,
+                epoch_id: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(retry.attempts, 0);
+
+    // The real run sends what the dry run previewed.
+    let response = owner_execute(&mut deps, &env, set_active_epoch(&env, None)).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(data, preview);
+    let dispatch: DispatchResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch {
+                dispatch_id: data.dispatch_id,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        data.payload_hash,
+        Binary(Sha256::digest(dispatch.payload.as_slice()).to_vec())
+    );
+}
+
+#[test]
+fn a_dry_run_sees_earlier_writes() {
+    let (mut deps, mut env) = setup();
+    owner_execute(&mut deps, &env, set_active_epoch(&env, None)).unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    owner_execute(&mut deps, &env, set_winner_list(&[ALICE], None)).unwrap();
+
+    let response =
+        owner_execute(&mut deps, &env, set_winner_list(&[ALICE, BOB], Some(true))).unwrap();
+    assert!(response.messages.is_empty());
+    let preview: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(preview.len(), 1);
+    assert_eq!(preview[0].dispatched, vec![BOB]);
+    assert_eq!(preview[0].skipped, vec![ALICE]);
+
+    // Bob's attempt was not recorded by the dry run.
+    let response = owner_execute(&mut deps, &env, set_winner_list(&[BOB], None)).unwrap();
+    let data: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(data[0].dispatched, vec![BOB]);
+    assert_eq!(data[0].dispatch_id, preview[0].dispatch_id);
+}
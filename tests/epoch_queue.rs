@@ -0,0 +1,171 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, EpochInfo, EpochQueueResponse, EpochResponse, EpochStatus, ExecuteMsg,
+    InstantiateMsg, PalomaMsg, QueryMsg, RetryPolicy,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+/// Schedules `epoch_id` on `arb` from `start` to `end` seconds after the current block.
+fn schedule(
+    deps: &mut Deps,
+    env: &Env,
+    sender: &str,
+    epoch_id: u32,
+    start: u64,
+    end: u64,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    let now = env.block.time.seconds();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::ScheduleEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::from(epoch_id),
+                competition_start: Uint256::from(now + start),
+                competition_end: Uint256::from(now + end),
+                entry_cnt: Uint256::zero(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            chain: None,
+        },
+    )
+}
+
+fn tick(deps: &mut Deps, env: &Env) -> Response<PalomaMsg> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("keeper", &[]),
+        ExecuteMsg::Tick {},
+    )
+    .unwrap()
+}
+
+fn queued(deps: &Deps, env: &Env) -> Vec<Uint256> {
+    let queue: EpochQueueResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpochQueue { chain: Chain::Arb },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    queue
+        .epochs
+        .into_iter()
+        .map(|epoch_info| epoch_info.epoch_id)
+        .collect()
+}
+
+fn attribute(response: &Response<PalomaMsg>, key: &str) -> Vec<String> {
+    response
+        .attributes
+        .iter()
+        .filter(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.clone())
+        .collect()
+}
+
+#[test]
+fn scheduled_epochs_stay_ordered_without_overlaps() {
+    let (mut deps, env) = setup();
+    assert!(matches!(
+        schedule(&mut deps, &env, "stranger", 1, 10, 20),
+        Err(ContractError::Unauthorized {})
+    ));
+    schedule(&mut deps, &env, "owner", 1, 10, 20).unwrap();
+    schedule(&mut deps, &env, "owner", 3, 40, 50).unwrap();
+    assert!(matches!(
+        schedule(&mut deps, &env, "owner", 2, 15, 30),
+        Err(ContractError::EpochOverlap { epoch_id }) if epoch_id == "1"
+    ));
+    assert!(matches!(
+        schedule(&mut deps, &env, "owner", 2, 25, 45),
+        Err(ContractError::EpochOverlap { epoch_id }) if epoch_id == "3"
+    ));
+    assert!(matches!(
+        schedule(&mut deps, &env, "owner", 2, 0, 30),
+        Err(ContractError::InvalidEpochWindow {})
+    ));
+    schedule(&mut deps, &env, "owner", 2, 25, 35).unwrap();
+    assert_eq!(
+        queued(&deps, &env),
+        vec![Uint256::from(1u8), Uint256::from(2u8), Uint256::from(3u8)]
+    );
+}
+
+#[test]
+fn tick_activates_the_epochs_that_have_started() {
+    let (mut deps, mut env) = setup();
+    schedule(&mut deps, &env, "owner", 1, 10, 20).unwrap();
+    schedule(&mut deps, &env, "owner", 2, 30, 40).unwrap();
+
+    let response = tick(&mut deps, &env);
+    assert!(response.messages.is_empty());
+    assert_eq!(queued(&deps, &env).len(), 2);
+
+    env.block.time = env.block.time.plus_seconds(15);
+    let response = tick(&mut deps, &env);
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(attribute(&response, "activated"), vec!["arb:1"]);
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched[0].action, "set_active_epoch");
+    assert_eq!(dispatched[0].dispatched, vec!["1"]);
+    assert_eq!(queued(&deps, &env), vec![Uint256::from(2u8)]);
+    let epoch: EpochResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(epoch.status, EpochStatus::Active);
+}
+
+#[test]
+fn tick_leaves_epochs_that_ended_in_the_queue() {
+    let (mut deps, mut env) = setup();
+    schedule(&mut deps, &env, "owner", 1, 10, 20).unwrap();
+    env.block.time = env.block.time.plus_seconds(30);
+    let response = tick(&mut deps, &env);
+    assert!(response.messages.is_empty());
+    assert_eq!(attribute(&response, "expired"), vec!["arb:1"]);
+    assert_eq!(queued(&deps, &env), vec![Uint256::one()]);
+}
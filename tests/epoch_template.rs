@@ -0,0 +1,175 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, EpochQueueResponse, EpochResponse, EpochStatus, EpochTemplate, ExecuteMsg,
+    InstantiateMsg, PalomaMsg, QueryMsg, RetryPolicy,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+/// Epochs of 100 seconds with 50 seconds between them, the first starting at `start_anchor`.
+fn set_template(deps: &mut Deps, env: &Env, start_anchor: u64) {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::SetEpochTemplate {
+            chain: Chain::Arb,
+            template: EpochTemplate {
+                duration: 100,
+                gap: 50,
+                prize_amount: Uint256::from(1_000u32),
+                start_anchor,
+            },
+        },
+    )
+    .unwrap();
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn start_next_epoch(deps: &mut Deps, env: &Env) -> Result<Response<PalomaMsg>, ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::StartNextEpoch {
+            chain: Chain::Arb,
+            dry_run: None,
+        },
+    )
+}
+
+/// Queued epochs as `(epoch_id, competition_start, competition_end)`.
+fn queue(deps: &Deps, env: &Env) -> Vec<(u64, u64, u64)> {
+    let response: EpochQueueResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpochQueue { chain: Chain::Arb },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let seconds = |value: Uint256| value.to_string().parse().unwrap();
+    response
+        .epochs
+        .into_iter()
+        .map(|epoch_info| {
+            (
+                seconds(epoch_info.epoch_id),
+                seconds(epoch_info.competition_start),
+                seconds(epoch_info.competition_end),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn epochs_follow_each_other_from_the_anchor() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    set_template(&mut deps, &env, now + 1_000);
+
+    start_next_epoch(&mut deps, &env).unwrap();
+    start_next_epoch(&mut deps, &env).unwrap();
+    assert_eq!(
+        queue(&deps, &env),
+        vec![(1, now + 1_000, now + 1_100), (2, now + 1_150, now + 1_250),]
+    );
+}
+
+#[test]
+fn ended_slots_are_skipped() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    // Slots [now - 250, now - 150] and [now - 100, now] have ended.
+    set_template(&mut deps, &env, now - 250);
+
+    start_next_epoch(&mut deps, &env).unwrap();
+    assert_eq!(queue(&deps, &env), vec![(1, now + 50, now + 150)]);
+}
+
+#[test]
+fn a_running_slot_is_activated_right_away() {
+    let (mut deps, env) = setup();
+    let now = env.block.time.seconds();
+    set_template(&mut deps, &env, now - 30);
+
+    start_next_epoch(&mut deps, &env).unwrap();
+    assert!(queue(&deps, &env).is_empty());
+    let epoch: EpochResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(epoch.status, EpochStatus::Active);
+    assert_eq!(
+        epoch.epoch_info.unwrap().competition_end,
+        Uint256::from(now + 70)
+    );
+}
+
+#[test]
+fn dry_run_queues_nothing() {
+    let (mut deps, env) = setup();
+    assert!(matches!(
+        start_next_epoch(&mut deps, &env),
+        Err(ContractError::EpochTemplateNotConfigured { .. })
+    ));
+    set_template(&mut deps, &env, env.block.time.seconds() + 1_000);
+
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::StartNextEpoch {
+            chain: Chain::Arb,
+            dry_run: Some(true),
+        },
+    )
+    .unwrap();
+    assert!(response
+        .attributes
+        .iter()
+        .any(|attribute| attribute.key == "dry_run"));
+    assert!(queue(&deps, &env).is_empty());
+}
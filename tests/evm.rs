@@ -0,0 +1,69 @@
+use juice_bot_eth_predictor_cw::evm::{normalize_address, parse_address, to_checksum};
+use juice_bot_eth_predictor_cw::ContractError;
+
+/// Checksummed addresses from the EIP-55 test vectors.
+const CHECKSUMMED: [&str; 4] = [
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+#[test]
+fn checksum_vectors_round_trip() {
+    for address in CHECKSUMMED {
+        assert_eq!(to_checksum(&parse_address(address).unwrap()), address);
+    }
+}
+
+#[test]
+fn single_case_addresses_are_normalized() {
+    for address in CHECKSUMMED {
+        let hex = &address[2..];
+        assert_eq!(
+            normalize_address(&format!("0x{}", hex.to_lowercase())).unwrap(),
+            address
+        );
+        assert_eq!(
+            normalize_address(&format!("0x{}", hex.to_uppercase())).unwrap(),
+            address
+        );
+    }
+}
+
+#[test]
+fn bad_checksum_is_rejected() {
+    for address in [
+        "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d358",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6fB",
+    ] {
+        assert!(matches!(
+            parse_address(address),
+            Err(ContractError::InvalidChecksum { .. })
+        ));
+    }
+}
+
+#[test]
+fn zero_address_is_rejected() {
+    assert!(matches!(
+        parse_address("0x0000000000000000000000000000000000000000"),
+        Err(ContractError::ZeroAddress {})
+    ));
+}
+
+#[test]
+fn malformed_addresses_are_rejected() {
+    for address in [
+        "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAe",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAedd",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeg",
+    ] {
+        assert!(matches!(
+            parse_address(address),
+            Err(ContractError::InvalidAddress { .. })
+        ));
+    }
+}
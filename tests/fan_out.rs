@@ -0,0 +1,124 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg, RetryPolicy,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn epoch_info(env: &Env) -> EpochInfo {
+    let now = env.block.time.seconds();
+    EpochInfo {
+        epoch_id: Uint256::one(),
+        competition_start: Uint256::from(now - 10),
+        competition_end: Uint256::from(now + 10),
+        entry_cnt: Uint256::one(),
+        prize_amount: Uint256::from(1_000u32),
+    }
+}
+
+fn set_active_epoch_all(
+    deps: &mut Deps,
+    env: &Env,
+    chains: Vec<Chain>,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetActiveEpochAll {
+            epoch_info: epoch_info(env),
+            chains,
+            signature: None,
+            dry_run: None,
+        },
+    )
+}
+
+fn skipped(response: &Response<PalomaMsg>) -> &str {
+    &response
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "skipped")
+        .unwrap()
+        .value
+}
+
+#[test]
+fn the_epoch_is_sent_to_each_selected_chain_once() {
+    let (mut deps, env) = setup();
+    let response =
+        set_active_epoch_all(&mut deps, &env, vec![Chain::Eth, Chain::Arb, Chain::Eth]).unwrap();
+    assert_eq!(response.messages.len(), 2);
+    assert_eq!(skipped(&response), "");
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    let jobs: Vec<(Chain, &str)> = dispatched
+        .iter()
+        .map(|data| (data.chain, data.job_id.as_str()))
+        .collect();
+    assert_eq!(jobs, vec![(Chain::Arb, "arb-job"), (Chain::Eth, "eth-job")]);
+    assert!(dispatched
+        .iter()
+        .all(|data| data.action == "set_active_epoch"));
+
+    assert!(matches!(
+        set_active_epoch_all(&mut deps, &env, vec![]),
+        Err(ContractError::NoChainsSelected {})
+    ));
+}
+
+#[test]
+fn a_pending_chain_does_not_block_the_others() {
+    let (mut deps, env) = setup();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: epoch_info(&env),
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+
+    let response = set_active_epoch_all(&mut deps, &env, vec![Chain::Arb, Chain::Eth]).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(skipped(&response), "arb");
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched.len(), 1);
+    assert_eq!(dispatched[0].chain, Chain::Eth);
+
+    assert!(matches!(
+        set_active_epoch_all(&mut deps, &env, vec![Chain::Arb, Chain::Eth]),
+        Err(ContractError::AllPending {})
+    ));
+}
@@ -0,0 +1,245 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, EpochInfo, ExecuteMsg, FeeConfig, FeeTotal, FeeTotalsResponse,
+    InstantiateMsg, PalomaMsg, ProposalAction, QueryMsg, RetryPolicy, Role, WinnerInfo,
+    WinnerListProposalResponse,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const TREASURY: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+
+/// Contract with a closed `arb` epoch 1 with a prize of 1000, of which `arb` takes a 10% fee.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![WINNER.to_string()],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    for msg in [
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+        ExecuteMsg::SetFeeConfig {
+            chain: Chain::Arb,
+            fee_bps: 1_000,
+            treasury: TREASURY.to_string(),
+        },
+        ExecuteMsg::GrantRole {
+            role: Role::Relayer,
+            address: "relayer".to_string(),
+        },
+    ] {
+        execute(deps.as_mut(), env.clone(), owner.clone(), msg).unwrap();
+    }
+    env.block.time = env.block.time.plus_seconds(20);
+    (deps, env)
+}
+
+fn winner(amount: u32, chain: Chain) -> WinnerInfo {
+    WinnerInfo {
+        winner: WINNER.to_string(),
+        claimable_amount: Uint256::from(amount),
+        chain: Some(chain),
+    }
+}
+
+fn set_winner_list(
+    deps: &mut Deps,
+    env: &Env,
+    winner_infos: Vec<WinnerInfo>,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos,
+            signature: None,
+            dry_run: None,
+        },
+    )
+}
+
+fn fee_totals(deps: &Deps, env: &Env) -> Vec<FeeTotal> {
+    let response: FeeTotalsResponse =
+        from_json(query(deps.as_ref(), env.clone(), QueryMsg::GetFeeTotals {}).unwrap()).unwrap();
+    response.totals
+}
+
+#[test]
+fn duplicate_winners_are_rejected() {
+    let (mut deps, env) = setup();
+    let result = set_winner_list(
+        &mut deps,
+        &env,
+        vec![winner(950, Chain::Arb), winner(1, Chain::Arb)],
+    );
+    assert!(matches!(
+        result,
+        Err(ContractError::DuplicateWinner { chain, .. }) if chain == "arb"
+    ));
+
+    // The same address may be paid once on each chain.
+    set_winner_list(
+        &mut deps,
+        &env,
+        vec![winner(10, Chain::Eth), winner(10, Chain::Arb)],
+    )
+    .unwrap();
+}
+
+#[test]
+fn claims_are_capped_at_the_prize_less_the_fee() {
+    let (mut deps, env) = setup();
+    let result = set_winner_list(&mut deps, &env, vec![winner(901, Chain::Arb)]);
+    assert!(matches!(
+        result,
+        Err(ContractError::PrizePoolExceeded { available, .. }) if available == "900"
+    ));
+}
+
+#[test]
+fn a_winner_skipped_on_one_chain_is_still_claimed_on_another() {
+    let (mut deps, env) = setup();
+    set_winner_list(&mut deps, &env, vec![winner(10, Chain::Eth)]).unwrap();
+
+    // The `eth` entry waits out its retry delay, but the `arb` one is sent and counted.
+    let result = set_winner_list(
+        &mut deps,
+        &env,
+        vec![winner(10, Chain::Eth), winner(950, Chain::Arb)],
+    );
+    assert!(matches!(
+        result,
+        Err(ContractError::PrizePoolExceeded { chain, .. }) if chain == "arb"
+    ));
+}
+
+#[test]
+fn fees_are_booked_once_the_dispatch_is_confirmed() {
+    let (mut deps, env) = setup();
+    let response = set_winner_list(&mut deps, &env, vec![winner(100, Chain::Arb)]).unwrap();
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched.len(), 1);
+    assert!(fee_totals(&deps, &env).is_empty());
+
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        ExecuteMsg::ReportExecution {
+            dispatch_id: dispatched[0].dispatch_id,
+            evm_tx_hash: "0x01".to_string(),
+            success: true,
+            error: None,
+        },
+    )
+    .unwrap();
+    let totals = fee_totals(&deps, &env);
+    assert_eq!(totals.len(), 1);
+    assert_eq!(totals[0].chain, Chain::Arb);
+    assert_eq!(totals[0].amount, Uint256::from(100u32));
+}
+
+#[test]
+fn fee_changes_need_approval_once_enabled() {
+    let (mut deps, env) = setup();
+    let owner = mock_info("owner", &[]);
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        ExecuteMsg::UpdateApprovalConfig {
+            approvers: vec!["alice".to_string()],
+            threshold: 1,
+            proposal_ttl: 3_600,
+        },
+    )
+    .unwrap();
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetFeeConfig {
+            chain: Chain::Arb,
+            fee_bps: 0,
+            treasury: TREASURY.to_string(),
+        },
+    )
+    .unwrap();
+    let fee_config = |deps: &Deps| -> FeeConfig {
+        from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetFeeConfig { chain: Chain::Arb },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    };
+    assert_eq!(fee_config(&deps).fee_bps, 1_000);
+
+    let proposal_id: u64 = response
+        .attributes
+        .iter()
+        .find(|attribute| attribute.key == "proposal_id")
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+    let proposal: WinnerListProposalResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetWinnerListProposal { proposal_id },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(matches!(
+        proposal.action,
+        ProposalAction::SetFeeConfig { fee_bps: 0, .. }
+    ));
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("alice", &[]),
+        ExecuteMsg::ApproveWinnerList { proposal_id },
+    )
+    .unwrap();
+    assert_eq!(fee_config(&deps).fee_bps, 0);
+}
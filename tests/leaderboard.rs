@@ -0,0 +1,213 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, EpochInfo, EpochResponse, ExecuteMsg, InstantiateMsg, LeaderboardOrder,
+    LeaderboardResponse, PlayerStatsResponse, QueryMsg, RetryPolicy, WinnerInfo,
+};
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const ALICE: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const BOB: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+const CAROL: &str = "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB";
+
+/// Contract with a closed `arb` epoch 1.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![ALICE.to_string()],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    (deps, env)
+}
+
+fn winner(address: &str, amount: u32, chain: Chain) -> WinnerInfo {
+    WinnerInfo {
+        winner: address.to_string(),
+        claimable_amount: Uint256::from(amount),
+        chain: Some(chain),
+    }
+}
+
+fn set_winner_list(deps: &mut Deps, env: &Env, winner_infos: Vec<WinnerInfo>) {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos,
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+}
+
+fn player_stats(deps: &Deps, env: &Env, address: &str) -> PlayerStatsResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::PlayerStats {
+                address: address.to_string(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn leaderboard(
+    deps: &Deps,
+    env: &Env,
+    order_by: LeaderboardOrder,
+    start_after: Option<&str>,
+) -> Vec<String> {
+    let response: LeaderboardResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::Leaderboard {
+                chain: None,
+                order_by,
+                limit: Some(2),
+                start_after: start_after.map(str::to_string),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    response
+        .players
+        .into_iter()
+        .map(|player| player.address)
+        .collect()
+}
+
+#[test]
+fn player_stats_count_wins_per_chain() {
+    let (mut deps, env) = setup();
+    set_winner_list(
+        &mut deps,
+        &env,
+        vec![
+            winner(ALICE, 30, Chain::Eth),
+            winner(BOB, 20, Chain::Arb),
+            winner(ALICE, 10, Chain::Arb),
+        ],
+    );
+
+    let stats = player_stats(&deps, &env, ALICE);
+    let overall = stats.overall.unwrap();
+    assert_eq!(overall.total_won, Uint256::from(40u32));
+    assert_eq!(overall.wins, 2);
+    assert_eq!(overall.best_rank, 1);
+    let chains: Vec<(Option<Chain>, Uint256, u32)> = stats
+        .chains
+        .iter()
+        .map(|player| (player.chain, player.total_won, player.best_rank))
+        .collect();
+    assert_eq!(
+        chains,
+        vec![
+            (Some(Chain::Eth), Uint256::from(30u32), 1),
+            (Some(Chain::Arb), Uint256::from(10u32), 3),
+        ]
+    );
+    assert!(player_stats(&deps, &env, CAROL).overall.is_none());
+}
+
+#[test]
+fn leaderboard_pages_follow_the_order() {
+    let (mut deps, env) = setup();
+    set_winner_list(
+        &mut deps,
+        &env,
+        vec![
+            winner(BOB, 20, Chain::Arb),
+            winner(ALICE, 30, Chain::Arb),
+            winner(CAROL, 10, Chain::Arb),
+        ],
+    );
+
+    let first = leaderboard(&deps, &env, LeaderboardOrder::TotalWon, None);
+    assert_eq!(first, vec![ALICE, BOB]);
+    let second = leaderboard(&deps, &env, LeaderboardOrder::TotalWon, Some(BOB));
+    assert_eq!(second, vec![CAROL]);
+
+    // Equal win counts tie in descending address order.
+    let first = leaderboard(&deps, &env, LeaderboardOrder::Wins, None);
+    assert_eq!(first, vec![BOB, CAROL]);
+    let second = leaderboard(&deps, &env, LeaderboardOrder::Wins, Some(CAROL));
+    assert_eq!(second, vec![ALICE]);
+}
+
+#[test]
+fn a_winner_skipped_on_one_chain_is_recorded_on_another() {
+    let (mut deps, env) = setup();
+    set_winner_list(&mut deps, &env, vec![winner(ALICE, 10, Chain::Eth)]);
+    // The `eth` entry waits out its retry delay; the `arb` one is sent.
+    set_winner_list(
+        &mut deps,
+        &env,
+        vec![winner(ALICE, 10, Chain::Eth), winner(ALICE, 5, Chain::Arb)],
+    );
+
+    let stats = player_stats(&deps, &env, ALICE);
+    assert_eq!(stats.overall.unwrap().wins, 2);
+    let epoch: EpochResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        epoch.winners,
+        vec![winner(ALICE, 10, Chain::Eth), winner(ALICE, 5, Chain::Arb)]
+    );
+}
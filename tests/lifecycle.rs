@@ -0,0 +1,197 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, EpochInfo, EpochResponse, EpochStatus, ExecuteMsg, InstantiateMsg,
+    PalomaMsg, QueryMsg, RetryPolicy, Role, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::GrantRole {
+            role: Role::Relayer,
+            address: "relayer".to_string(),
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+/// Activates epoch 1 on `arb`, running from 10 seconds before to 10 seconds after `start`.
+fn set_active_epoch(
+    deps: &mut Deps,
+    env: &Env,
+    start: u64,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(start - 10),
+                competition_end: Uint256::from(start + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+}
+
+fn set_winner_list(deps: &mut Deps, env: &Env) -> Result<Response<PalomaMsg>, ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: vec![WinnerInfo {
+                winner: WINNER.to_string(),
+                claimable_amount: Uint256::from(1_000u32),
+                chain: Some(Chain::Arb),
+            }],
+            signature: None,
+            dry_run: None,
+        },
+    )
+}
+
+fn status(deps: &Deps, env: &Env) -> EpochStatus {
+    let epoch: EpochResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    epoch.status
+}
+
+fn is_transition(
+    result: Result<Response<PalomaMsg>, ContractError>,
+    expected_from: &str,
+    expected_to: &str,
+) -> bool {
+    matches!(
+        result,
+        Err(ContractError::InvalidEpochTransition { from, to })
+            if from == expected_from && to == expected_to
+    )
+}
+
+#[test]
+fn epochs_run_from_active_to_paid() {
+    let (mut deps, mut env) = setup();
+    let start = env.block.time.seconds();
+    set_active_epoch(&mut deps, &env, start).unwrap();
+    assert_eq!(status(&deps, &env), EpochStatus::Active);
+    assert!(is_transition(
+        set_winner_list(&mut deps, &env),
+        "active",
+        "settled"
+    ));
+
+    env.block.time = env.block.time.plus_seconds(20);
+    assert_eq!(status(&deps, &env), EpochStatus::Closed);
+    let response = set_winner_list(&mut deps, &env).unwrap();
+    assert_eq!(status(&deps, &env), EpochStatus::Settled);
+
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        ExecuteMsg::ReportExecution {
+            dispatch_id: dispatched[0].dispatch_id,
+            evm_tx_hash: "0x01".to_string(),
+            success: true,
+            error: None,
+        },
+    )
+    .unwrap();
+    assert_eq!(status(&deps, &env), EpochStatus::Paid);
+
+    // A paid epoch cannot be started again once its retry delay is over.
+    env.block.time = env.block.time.plus_seconds(60);
+    assert!(is_transition(
+        set_active_epoch(&mut deps, &env, env.block.time.seconds()),
+        "paid",
+        "active"
+    ));
+}
+
+#[test]
+fn winners_need_an_activated_epoch() {
+    let (mut deps, env) = setup();
+    assert!(is_transition(
+        set_winner_list(&mut deps, &env),
+        "none",
+        "settled"
+    ));
+}
+
+#[test]
+fn the_owner_can_close_an_epoch_early() {
+    let (mut deps, env) = setup();
+    set_active_epoch(&mut deps, &env, env.block.time.seconds()).unwrap();
+    let close = |deps: &mut Deps| {
+        owner_execute(
+            deps,
+            &env,
+            ExecuteMsg::CloseEpoch {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+    };
+    close(&mut deps).unwrap();
+    assert_eq!(status(&deps, &env), EpochStatus::Closed);
+    assert!(is_transition(close(&mut deps), "closed", "closed"));
+    set_winner_list(&mut deps, &env).unwrap();
+    assert_eq!(status(&deps, &env), EpochStatus::Settled);
+}
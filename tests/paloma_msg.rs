@@ -0,0 +1,165 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{from_json, to_json_string, Binary, CosmosMsg, Decimal, Uint128};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate};
+use juice_bot_eth_predictor_cw::msg::{
+    CancelTx, CreateDenomMsg, ExecuteJob, ExecuteMsg, InstantiateMsg, Metadata, MintMsg, PalomaMsg,
+    PalomaMsgV1, PalomaMsgV2, PalomaMsgVersion, RetryPolicy, SendTx,
+};
+
+const ADDRESS: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn metadata() -> Metadata {
+    Metadata {
+        creator: "paloma1creator".to_string(),
+        signers: vec![ADDRESS.to_string()],
+    }
+}
+
+fn execute_job(assign_count: Option<u32>, gas_estimate: Option<u64>) -> PalomaMsg {
+    PalomaMsg::V2(PalomaMsgV2::SchedulerMsg {
+        execute_job: ExecuteJob {
+            job_id: "eth-job".to_string(),
+            payload: Binary(vec![1, 2]),
+            assign_count,
+            gas_estimate,
+        },
+    })
+}
+
+/// Deserializes the fixture into `expected` and serializes `expected` back into the fixture.
+fn assert_round_trip(fixture: &str, expected: PalomaMsg) {
+    let fixture = fixture.trim();
+    assert_eq!(from_json::<PalomaMsg>(fixture).unwrap(), expected);
+    assert_eq!(to_json_string(&expected).unwrap(), fixture);
+}
+
+#[test]
+fn v1_execute_job() {
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v1_execute_job.json"),
+        PalomaMsg::V1(PalomaMsgV1 {
+            job_id: "eth-job".to_string(),
+            payload: Binary(vec![1, 2]),
+            metadata: metadata(),
+        }),
+    );
+}
+
+#[test]
+fn v2_execute_job() {
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v2_execute_job.json"),
+        execute_job(Some(3), Some(250_000)),
+    );
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v2_execute_job_minimal.json"),
+        execute_job(None, None),
+    );
+}
+
+#[test]
+fn v2_skyway() {
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v2_skyway_send_tx.json"),
+        PalomaMsg::V2(PalomaMsgV2::SkywayMsg {
+            send_tx: Some(SendTx {
+                remote_chain_destination_address: ADDRESS.to_string(),
+                amount: "1000000ugrain".to_string(),
+                chain_reference_id: "arbitrum-main".to_string(),
+            }),
+            cancel_tx: None,
+        }),
+    );
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v2_skyway_cancel_tx.json"),
+        PalomaMsg::V2(PalomaMsgV2::SkywayMsg {
+            send_tx: None,
+            cancel_tx: Some(CancelTx { transaction_id: 42 }),
+        }),
+    );
+}
+
+#[test]
+fn v2_token_factory() {
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v2_token_factory_create_denom.json"),
+        PalomaMsg::V2(PalomaMsgV2::TokenFactoryMsg {
+            create_denom: Some(CreateDenomMsg {
+                subdenom: "juice".to_string(),
+            }),
+            mint_tokens: None,
+        }),
+    );
+    assert_round_trip(
+        include_str!("fixtures/paloma_msg/v2_token_factory_mint_tokens.json"),
+        PalomaMsg::V2(PalomaMsgV2::TokenFactoryMsg {
+            create_denom: None,
+            mint_tokens: Some(MintMsg {
+                denom: "factory/paloma1contract/juice".to_string(),
+                amount: Uint128::new(500),
+                mint_to_address: "paloma1recipient".to_string(),
+            }),
+        }),
+    );
+}
+
+#[test]
+fn dispatch_uses_configured_version() {
+    let mut deps = mock_dependencies();
+    let info = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![ADDRESS.to_string()],
+        },
+    )
+    .unwrap();
+    let set_eth_paloma = ExecuteMsg::SetEthPaloma { dry_run: None };
+
+    let response = execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        set_eth_paloma.clone(),
+    )
+    .unwrap();
+    let CosmosMsg::Custom(PalomaMsg::V1(msg)) = &response.messages[0].msg else {
+        panic!("expected a v1 message");
+    };
+    assert_eq!(msg.job_id, "eth-job");
+    assert_eq!(msg.metadata, metadata());
+
+    let version = PalomaMsgVersion::V2 {
+        assign_count: Some(3),
+        gas_estimate: Some(250_000),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        info.clone(),
+        ExecuteMsg::SetPalomaMsgVersion { version },
+    )
+    .unwrap();
+    let response = execute(deps.as_mut(), mock_env(), info, set_eth_paloma).unwrap();
+    let CosmosMsg::Custom(PalomaMsg::V2(PalomaMsgV2::SchedulerMsg { execute_job })) =
+        &response.messages[0].msg
+    else {
+        panic!("expected a scheduler message");
+    };
+    assert_eq!(execute_job.job_id, "eth-job");
+    assert_eq!(execute_job.assign_count, Some(3));
+    assert_eq!(execute_job.gas_estimate, Some(250_000));
+}
@@ -0,0 +1,160 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    coin, coins, from_json, Coin, CosmosMsg, Decimal, Env, MemoryStorage, OwnedDeps, Response,
+    Uint256,
+};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg, PalomaMsgV2, PalomaMsgVersion,
+    PrizePoolResponse, QueryMsg, RetryPolicy, SendTx,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const COMPETITION: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const V2: PalomaMsgVersion = PalomaMsgVersion::V2 {
+    assign_count: None,
+    gas_estimate: None,
+};
+
+/// Contract sending `version` messages with `arb` configured for Skyway.
+fn setup(version: PalomaMsgVersion) -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: Some(version),
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetChainConfig {
+            chain: Chain::Arb,
+            chain_reference_id: "arbitrum-main".to_string(),
+            competition_contract: COMPETITION.to_string(),
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn set_active_epoch(deps: &mut Deps, env: &Env) {
+    let now = env.block.time.seconds();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+}
+
+fn fund(deps: &mut Deps, env: &Env, funds: &[Coin]) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("sponsor", funds),
+        ExecuteMsg::FundPrizePool {
+            chain: Chain::Arb,
+            epoch_id: Uint256::one(),
+        },
+    )
+}
+
+fn prize_pool(deps: &Deps, env: &Env) -> PrizePoolResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetPrizePool {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn funding_is_bridged_and_recorded() {
+    let (mut deps, env) = setup(V2);
+    set_active_epoch(&mut deps, &env);
+    let response = fund(&mut deps, &env, &coins(400, "ugrain")).unwrap();
+    assert_eq!(
+        response.messages[0].msg,
+        CosmosMsg::Custom(PalomaMsg::V2(PalomaMsgV2::SkywayMsg {
+            send_tx: Some(SendTx {
+                remote_chain_destination_address: COMPETITION.to_string(),
+                amount: "400ugrain".to_string(),
+                chain_reference_id: "arbitrum-main".to_string(),
+            }),
+            cancel_tx: None,
+        }))
+    );
+    fund(&mut deps, &env, &coins(100, "ugrain")).unwrap();
+
+    let pool = prize_pool(&deps, &env);
+    assert_eq!(pool.committed, Some(Uint256::from(1_000u32)));
+    assert_eq!(pool.funded, coins(500, "ugrain"));
+}
+
+#[test]
+fn funding_needs_a_single_coin_and_a_fundable_epoch() {
+    let (mut deps, env) = setup(V2);
+    assert!(matches!(
+        fund(&mut deps, &env, &coins(400, "ugrain")),
+        Err(ContractError::EpochNotFundable { .. })
+    ));
+    set_active_epoch(&mut deps, &env);
+    for funds in [
+        vec![],
+        coins(0, "ugrain"),
+        vec![coin(1, "ugrain"), coin(1, "uatom")],
+    ] {
+        assert!(matches!(
+            fund(&mut deps, &env, &funds),
+            Err(ContractError::InvalidFunds {})
+        ));
+    }
+    assert!(prize_pool(&deps, &env).funded.is_empty());
+}
+
+#[test]
+fn funding_needs_skyway() {
+    let (mut deps, env) = setup(PalomaMsgVersion::V1);
+    set_active_epoch(&mut deps, &env);
+    assert!(matches!(
+        fund(&mut deps, &env, &coins(400, "ugrain")),
+        Err(ContractError::SkywayNotSupported {})
+    ));
+}
@@ -0,0 +1,152 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, Decimal, Env, MemoryStorage, OwnedDeps, Reply, ReplyOn, SubMsgResponse,
+    SubMsgResult, Uint256,
+};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query, reply};
+use juice_bot_eth_predictor_cw::msg::{
+    DispatchData, DispatchResponse, DispatchStatus, EpochInfo, ExecuteMsg, InstantiateMsg,
+    QueryMsg, RetryAction, RetryPolicy, RetryStateResponse, RetryStatus,
+};
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+/// Contract with epoch 1 sent to `arb`, returned with the id of its dispatch.
+fn setup(rollback_on_submit_failure: bool) -> (Deps, Env, u64) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: Some(rollback_on_submit_failure),
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    assert_eq!(response.messages[0].id, data.dispatch_id);
+    assert_eq!(response.messages[0].reply_on, ReplyOn::Always);
+    (deps, env, data.dispatch_id)
+}
+
+fn dispatch(deps: &Deps, env: &Env, dispatch_id: u64) -> DispatchResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch { dispatch_id },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn retry_state(deps: &Deps, env: &Env) -> RetryStateResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRetryState {
+                action: RetryAction::SetActiveEpoch,
+                key: "1".to_string(),
+                chain: None,
+                epoch_id: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn reject(deps: &mut Deps, env: &Env, dispatch_id: u64) {
+    reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: dispatch_id,
+            result: SubMsgResult::Err("job not found".to_string()),
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn an_accepted_submit_is_recorded() {
+    let (mut deps, env, dispatch_id) = setup(false);
+    assert_eq!(dispatch(&deps, &env, dispatch_id).submitted, None);
+    reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: dispatch_id,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    )
+    .unwrap();
+    let submitted = dispatch(&deps, &env, dispatch_id);
+    assert_eq!(submitted.submitted, Some(true));
+    assert_eq!(submitted.status, DispatchStatus::Pending);
+}
+
+#[test]
+fn a_rejected_submit_fails_the_dispatch() {
+    let (mut deps, env, dispatch_id) = setup(false);
+    reject(&mut deps, &env, dispatch_id);
+    let rejected = dispatch(&deps, &env, dispatch_id);
+    assert_eq!(rejected.submitted, Some(false));
+    assert_eq!(rejected.submit_error.as_deref(), Some("job not found"));
+    assert_eq!(rejected.status, DispatchStatus::Failed);
+
+    // Without the rollback option the attempt still counts.
+    let retry = retry_state(&deps, &env);
+    assert_eq!(retry.attempts, 1);
+    assert_eq!(retry.status, RetryStatus::Waiting);
+}
+
+#[test]
+fn a_rejected_submit_can_roll_back_the_attempt() {
+    let (mut deps, env, dispatch_id) = setup(true);
+    reject(&mut deps, &env, dispatch_id);
+    assert_eq!(
+        dispatch(&deps, &env, dispatch_id).status,
+        DispatchStatus::Failed
+    );
+    let retry = retry_state(&deps, &env);
+    assert_eq!(retry.attempts, 0);
+    assert_eq!(retry.status, RetryStatus::Ready);
+}
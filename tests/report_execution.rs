@@ -0,0 +1,176 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    DispatchData, DispatchResponse, DispatchStatus, EpochInfo, ExecuteMsg, InstantiateMsg,
+    PalomaMsg, QueryMsg, RetryAction, RetryPolicy, RetryStateResponse, RetryStatus, Role,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::GrantRole {
+            role: Role::Relayer,
+            address: "relayer".to_string(),
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+/// Activates epoch 1 on `arb` and returns the id of its dispatch.
+fn set_active_epoch(deps: &mut Deps, env: &Env) -> Result<u64, ContractError> {
+    let now = env.block.time.seconds();
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )?;
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    Ok(data.dispatch_id)
+}
+
+fn report(
+    deps: &mut Deps,
+    env: &Env,
+    sender: &str,
+    dispatch_id: u64,
+    success: bool,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::ReportExecution {
+            dispatch_id,
+            evm_tx_hash: "0x01".to_string(),
+            success,
+            error: (!success).then(|| "reverted".to_string()),
+        },
+    )
+}
+
+fn dispatch(deps: &Deps, env: &Env, dispatch_id: u64) -> DispatchResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch { dispatch_id },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn retry_state(deps: &Deps, env: &Env) -> RetryStateResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRetryState {
+                action: RetryAction::SetActiveEpoch,
+                key: "1".to_string(),
+                chain: None,
+                epoch_id: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn only_relayers_report() {
+    let (mut deps, env) = setup();
+    let dispatch_id = set_active_epoch(&mut deps, &env).unwrap();
+    for sender in ["owner", "stranger"] {
+        assert!(matches!(
+            report(&mut deps, &env, sender, dispatch_id, true),
+            Err(ContractError::Unauthorized {})
+        ));
+    }
+    assert_eq!(
+        dispatch(&deps, &env, dispatch_id).status,
+        DispatchStatus::Pending
+    );
+}
+
+#[test]
+fn a_confirmed_dispatch_resets_attempts_but_keeps_the_delay() {
+    let (mut deps, env) = setup();
+    let dispatch_id = set_active_epoch(&mut deps, &env).unwrap();
+    assert_eq!(retry_state(&deps, &env).attempts, 1);
+
+    report(&mut deps, &env, "relayer", dispatch_id, true).unwrap();
+    let confirmed = dispatch(&deps, &env, dispatch_id);
+    assert_eq!(confirmed.status, DispatchStatus::Confirmed);
+    assert_eq!(confirmed.evm_tx_hash.as_deref(), Some("0x01"));
+    assert_eq!(confirmed.reported_at, Some(env.block.time));
+    let retry = retry_state(&deps, &env);
+    assert_eq!(retry.attempts, 0);
+    assert_eq!(retry.status, RetryStatus::Waiting);
+
+    assert!(matches!(
+        report(&mut deps, &env, "relayer", dispatch_id, false),
+        Err(ContractError::DispatchNotPending {})
+    ));
+}
+
+#[test]
+fn a_failed_dispatch_reopens_its_retry_keys() {
+    let (mut deps, env) = setup();
+    let dispatch_id = set_active_epoch(&mut deps, &env).unwrap();
+    assert_eq!(retry_state(&deps, &env).status, RetryStatus::Waiting);
+
+    report(&mut deps, &env, "relayer", dispatch_id, false).unwrap();
+    let failed = dispatch(&deps, &env, dispatch_id);
+    assert_eq!(failed.status, DispatchStatus::Failed);
+    assert_eq!(failed.error.as_deref(), Some("reverted"));
+    assert_eq!(retry_state(&deps, &env).status, RetryStatus::Ready);
+
+    // The epoch may be sent again without waiting out the delay.
+    let resent = set_active_epoch(&mut deps, &env).unwrap();
+    assert_ne!(resent, dispatch_id);
+    let retry = retry_state(&deps, &env);
+    assert_eq!(retry.attempts, 2);
+    assert_eq!(retry.status, RetryStatus::Waiting);
+}
@@ -0,0 +1,156 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    DispatchData, DispatchResponse, DispatchStatus, ExecuteMsg, InstantiateMsg, PalomaMsg,
+    QueryMsg, RetryPolicy, Role,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+/// Contract with a pending `set_paloma` dispatch on `arb`, returned with its id.
+fn setup() -> (Deps, Env, u64) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    for (role, address) in [(Role::Relayer, "relayer"), (Role::Operator, "operator")] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner.clone(),
+            ExecuteMsg::GrantRole {
+                role,
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    }
+    let response = execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetArbPaloma { dry_run: None },
+    )
+    .unwrap();
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    (deps, env, data.dispatch_id)
+}
+
+fn resend(
+    deps: &mut Deps,
+    env: &Env,
+    sender: &str,
+    dispatch_id: u64,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(sender, &[]),
+        ExecuteMsg::Resend {
+            dispatch_id,
+            dry_run: None,
+        },
+    )
+}
+
+fn report(deps: &mut Deps, env: &Env, dispatch_id: u64, success: bool) {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        ExecuteMsg::ReportExecution {
+            dispatch_id,
+            evm_tx_hash: "0x01".to_string(),
+            success,
+            error: None,
+        },
+    )
+    .unwrap();
+}
+
+fn dispatch(deps: &Deps, env: &Env, dispatch_id: u64) -> DispatchResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch { dispatch_id },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn pending_dispatches_are_resent_after_the_delay() {
+    let (mut deps, mut env, dispatch_id) = setup();
+    assert!(matches!(
+        resend(&mut deps, &env, "stranger", dispatch_id),
+        Err(ContractError::Unauthorized {})
+    ));
+    assert!(matches!(
+        resend(&mut deps, &env, "owner", dispatch_id),
+        Err(ContractError::AllPending {})
+    ));
+
+    env.block.time = env.block.time.plus_seconds(61);
+    let response = resend(&mut deps, &env, "operator", dispatch_id).unwrap();
+    assert_eq!(response.messages.len(), 1);
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(data.dispatch_id, dispatch_id);
+    let resent = dispatch(&deps, &env, dispatch_id);
+    assert_eq!(resent.resend_count, 1);
+    assert_eq!(resent.last_sent_at, env.block.time);
+    assert_eq!(resent.status, DispatchStatus::Pending);
+
+    // With `max_attempts` 3 the first send and two resends use up every attempt.
+    env.block.time = env.block.time.plus_seconds(61);
+    resend(&mut deps, &env, "owner", dispatch_id).unwrap();
+    env.block.time = env.block.time.plus_seconds(61);
+    assert!(matches!(
+        resend(&mut deps, &env, "owner", dispatch_id),
+        Err(ContractError::RetryExhausted {})
+    ));
+}
+
+#[test]
+fn failed_dispatches_are_resent_at_once() {
+    let (mut deps, env, dispatch_id) = setup();
+    report(&mut deps, &env, dispatch_id, false);
+    resend(&mut deps, &env, "owner", dispatch_id).unwrap();
+    let resent = dispatch(&deps, &env, dispatch_id);
+    assert_eq!(resent.status, DispatchStatus::Pending);
+    assert_eq!(resent.evm_tx_hash, None);
+    assert_eq!(resent.reported_at, None);
+}
+
+#[test]
+fn confirmed_dispatches_are_not_resent() {
+    let (mut deps, mut env, dispatch_id) = setup();
+    report(&mut deps, &env, dispatch_id, true);
+    env.block.time = env.block.time.plus_seconds(61);
+    assert!(matches!(
+        resend(&mut deps, &env, "owner", dispatch_id),
+        Err(ContractError::DispatchConfirmed {})
+    ));
+}
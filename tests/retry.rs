@@ -0,0 +1,232 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{from_json, Addr, Decimal, Env, Timestamp, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, migrate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, EpochInfo, ExecuteMsg, InstantiateMsg, Metadata, MigrateMsg, QueryMsg, RetryAction,
+    RetryPolicy, RetryStateResponse, RetryStatus, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::state::{
+    LegacyState, RetryState, LEGACY_STATE, LEGACY_WITHDRAW_TIMESTAMP, WITHDRAW_TIMESTAMP,
+};
+
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        base_delay: 60,
+        multiplier: Decimal::percent(200),
+        max_delay: 300,
+        max_attempts: 3,
+    }
+}
+
+#[test]
+fn delay_grows_by_the_multiplier_up_to_the_cap() {
+    let policy = policy();
+    assert_eq!(policy.delay(0), 60);
+    assert_eq!(policy.delay(1), 60);
+    assert_eq!(policy.delay(2), 120);
+    assert_eq!(policy.delay(3), 240);
+    assert_eq!(policy.delay(4), 300);
+    assert_eq!(policy.delay(u32::MAX), 300);
+}
+
+#[test]
+fn status_waits_out_the_delay_then_fails_after_max_attempts() {
+    let policy = policy();
+    let sent = Timestamp::from_seconds(1_000);
+    let retry = |attempts, reopened| RetryState {
+        timestamp: sent,
+        attempts,
+        reopened,
+    };
+    assert_eq!(
+        retry(1, false).status(&policy, sent.plus_seconds(60)),
+        RetryStatus::Waiting
+    );
+    assert_eq!(
+        retry(1, false).status(&policy, sent.plus_seconds(61)),
+        RetryStatus::Ready
+    );
+    assert_eq!(retry(2, true).status(&policy, sent), RetryStatus::Ready);
+    // Failed is terminal: neither time nor a failed report reopens it.
+    for reopened in [false, true] {
+        assert_eq!(
+            retry(3, reopened).status(&policy, sent.plus_seconds(u32::MAX as u64)),
+            RetryStatus::Failed
+        );
+    }
+}
+
+fn retry_state(deps: cosmwasm_std::Deps, env: &Env, epoch_id: u8) -> RetryStateResponse {
+    from_json(
+        query(
+            deps,
+            env.clone(),
+            QueryMsg::GetRetryState {
+                action: RetryAction::SetWinner,
+                key: WINNER.to_lowercase(),
+                chain: None,
+                epoch_id: Some(Uint256::from(epoch_id)),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn winner_attempts_fail_per_epoch() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                max_attempts: 1,
+                ..policy()
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![WINNER.to_string()],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    for epoch_id in [1u8, 2] {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            owner.clone(),
+            ExecuteMsg::SetActiveEpochAll {
+                epoch_info: EpochInfo {
+                    epoch_id: Uint256::from(epoch_id),
+                    competition_start: Uint256::from(now - 10 + u64::from(epoch_id) * 20),
+                    competition_end: Uint256::from(now + u64::from(epoch_id) * 20),
+                    entry_cnt: Uint256::one(),
+                    prize_amount: Uint256::from(1_000u32),
+                },
+                chains: vec![Chain::Arb],
+                signature: None,
+                dry_run: None,
+            },
+        )
+        .unwrap();
+    }
+    env.block.time = env.block.time.plus_seconds(100);
+
+    let set_winner_list = |epoch_id: u8| ExecuteMsg::SetWinnerList {
+        epoch_id: Uint256::from(epoch_id),
+        winner_infos: vec![WinnerInfo {
+            winner: WINNER.to_string(),
+            claimable_amount: Uint256::from(10u8),
+            chain: None,
+        }],
+        signature: None,
+        dry_run: None,
+    };
+    assert_eq!(
+        retry_state(deps.as_ref(), &env, 1).status,
+        RetryStatus::Ready
+    );
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        set_winner_list(1),
+    )
+    .unwrap();
+    let state = retry_state(deps.as_ref(), &env, 1);
+    assert_eq!(state.attempts, 1);
+    assert_eq!(state.status, RetryStatus::Failed);
+
+    // Another epoch's payout starts with its own attempts.
+    assert_eq!(
+        retry_state(deps.as_ref(), &env, 2).status,
+        RetryStatus::Ready
+    );
+    execute(deps.as_mut(), env.clone(), owner, set_winner_list(2)).unwrap();
+    assert_eq!(retry_state(deps.as_ref(), &env, 2).attempts, 1);
+}
+
+#[test]
+fn migrate_converts_the_retry_delay_into_a_policy() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    LEGACY_STATE
+        .save(
+            &mut deps.storage,
+            &LegacyState {
+                retry_delay: 30,
+                job_eth_id: "eth-job".to_string(),
+                job_arb_id: "arb-job".to_string(),
+                owner: Addr::unchecked("owner"),
+                metadata: Metadata {
+                    creator: "paloma1creator".to_string(),
+                    signers: vec![],
+                },
+            },
+        )
+        .unwrap();
+    let sent = env.block.time;
+    let winner_key = (WINNER.to_string(), "set_winner".to_string());
+    let epoch_key = ("1".to_string(), "set_active_epoch".to_string());
+    for key in [winner_key.clone(), epoch_key.clone()] {
+        LEGACY_WITHDRAW_TIMESTAMP
+            .save(&mut deps.storage, key, &sent)
+            .unwrap();
+    }
+
+    migrate(
+        deps.as_mut(),
+        env.clone(),
+        MigrateMsg { max_attempts: None },
+    )
+    .unwrap();
+    let policy: RetryPolicy = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRetryPolicy { action: None },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        policy,
+        RetryPolicy {
+            base_delay: 30,
+            multiplier: Decimal::one(),
+            max_delay: 30,
+            max_attempts: u32::MAX,
+        }
+    );
+    assert_eq!(
+        WITHDRAW_TIMESTAMP.load(&deps.storage, epoch_key).unwrap(),
+        RetryState {
+            timestamp: sent,
+            attempts: 1,
+            reopened: false,
+        }
+    );
+    assert!(WITHDRAW_TIMESTAMP
+        .may_load(&deps.storage, winner_key)
+        .unwrap()
+        .is_none());
+
+    // A second run finds the current state and leaves it as it is.
+    migrate(
+        deps.as_mut(),
+        env,
+        MigrateMsg {
+            max_attempts: Some(1),
+        },
+    )
+    .unwrap();
+}
@@ -0,0 +1,168 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DispatchData, EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg, RetryAction,
+    RetryPolicy, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const ALICE: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const BOB: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+
+fn retry_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        base_delay: 60,
+        multiplier: Decimal::one(),
+        max_delay: 60,
+        max_attempts,
+    }
+}
+
+/// Contract with a closed `arb` epoch 1.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: retry_policy(3),
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![ALICE.to_string()],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    (deps, env)
+}
+
+fn winner(address: &str, chain: Chain) -> WinnerInfo {
+    WinnerInfo {
+        winner: address.to_string(),
+        claimable_amount: Uint256::from(10u8),
+        chain: Some(chain),
+    }
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn set_winner_list(
+    deps: &mut Deps,
+    env: &Env,
+    winner_infos: Vec<WinnerInfo>,
+) -> Result<Vec<DispatchData>, ContractError> {
+    let response = owner_execute(
+        deps,
+        env,
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos,
+            signature: None,
+            dry_run: None,
+        },
+    )?;
+    Ok(from_json(response.data.unwrap()).unwrap())
+}
+
+#[test]
+fn winners_are_sent_to_the_job_of_their_chain() {
+    let (mut deps, env) = setup();
+    let dispatched = set_winner_list(
+        &mut deps,
+        &env,
+        vec![winner(ALICE, Chain::Eth), winner(BOB, Chain::Arb)],
+    )
+    .unwrap();
+    let routes: Vec<(Chain, &str, Vec<String>)> = dispatched
+        .iter()
+        .map(|data| (data.chain, data.job_id.as_str(), data.dispatched.clone()))
+        .collect();
+    assert_eq!(
+        routes,
+        vec![
+            (Chain::Arb, "arb-job", vec![BOB.to_string()]),
+            (Chain::Eth, "eth-job", vec![ALICE.to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn a_chain_without_a_job_sends_nothing() {
+    let (mut deps, env) = setup();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetJobId {
+            chain: Chain::Arb,
+            job_id: String::new(),
+        },
+    )
+    .unwrap();
+    let result = set_winner_list(
+        &mut deps,
+        &env,
+        vec![winner(ALICE, Chain::Eth), winner(BOB, Chain::Arb)],
+    );
+    assert!(matches!(
+        result,
+        Err(ContractError::JobNotConfigured { chain }) if chain == "arb"
+    ));
+}
+
+#[test]
+fn resends_follow_the_action_policy_on_every_chain() {
+    let (mut deps, mut env) = setup();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::UpdateRetryPolicy {
+            action: Some(RetryAction::SetWinner),
+            retry_policy: retry_policy(1),
+        },
+    )
+    .unwrap();
+    let dispatched = set_winner_list(&mut deps, &env, vec![winner(BOB, Chain::Arb)]).unwrap();
+    env.block.time = env.block.time.plus_seconds(120);
+    let result = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::Resend {
+            dispatch_id: dispatched[0].dispatch_id,
+            dry_run: None,
+        },
+    );
+    assert!(matches!(result, Err(ContractError::RetryExhausted {})));
+}
@@ -0,0 +1,239 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DecodePayloadResponse, DispatchData, DispatchResponse, EpochInfo, ExecuteMsg,
+    InstantiateMsg, PalomaMsg, QueryMsg, RetryPolicy, SeasonInfo, SeasonResponse, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const ALICE: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const BOB: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+const CAROL: &str = "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB";
+
+/// Contract at `start + 50` with `arb` epochs 1, won by Alice then Bob, and 2, won by Bob then
+/// Carol, both settled.
+fn setup() -> (Deps, Env, u64) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let start = env.block.time.seconds();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    for (epoch_id, winners) in [(1u8, [ALICE, BOB]), (2, [BOB, CAROL])] {
+        let now = env.block.time.seconds();
+        owner_execute(
+            &mut deps,
+            &env,
+            ExecuteMsg::SetActiveEpoch {
+                epoch_info: EpochInfo {
+                    epoch_id: Uint256::from(epoch_id),
+                    competition_start: Uint256::from(now - 5),
+                    competition_end: Uint256::from(now + 10),
+                    entry_cnt: Uint256::one(),
+                    prize_amount: Uint256::from(1_000u32),
+                },
+                signature: None,
+                dry_run: None,
+            },
+        )
+        .unwrap();
+        env.block.time = env.block.time.plus_seconds(20);
+        owner_execute(
+            &mut deps,
+            &env,
+            ExecuteMsg::SetWinnerList {
+                epoch_id: Uint256::from(epoch_id),
+                winner_infos: winners
+                    .iter()
+                    .map(|winner| WinnerInfo {
+                        winner: winner.to_string(),
+                        claimable_amount: Uint256::from(10u8),
+                        chain: Some(Chain::Arb),
+                    })
+                    .collect(),
+                signature: None,
+                dry_run: None,
+            },
+        )
+        .unwrap();
+    }
+    env.block.time = env.block.time.plus_seconds(10);
+    (deps, env, start)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn set_season(
+    deps: &mut Deps,
+    env: &Env,
+    start: u64,
+    end: u64,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::SetSeason {
+            season_id: 1,
+            season_info: SeasonInfo {
+                start,
+                end,
+                epochs: vec![Uint256::one(), Uint256::from(2u8)],
+                points_per_rank: vec![3, 1],
+                prize_amount: Uint256::from(800u32),
+            },
+        },
+    )
+}
+
+fn season(deps: &Deps, env: &Env) -> SeasonResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetSeason { season_id: 1 },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn epochs_must_run_within_the_season() {
+    let (mut deps, env, start) = setup();
+    for (season_start, season_end) in [(start, start + 100), (start - 10, start + 20)] {
+        assert!(matches!(
+            set_season(&mut deps, &env, season_start, season_end),
+            Err(ContractError::InvalidSeason { .. })
+        ));
+    }
+    set_season(&mut deps, &env, start - 10, start + 100).unwrap();
+}
+
+#[test]
+fn players_score_points_for_their_ranks() {
+    let (mut deps, env, start) = setup();
+    set_season(&mut deps, &env, start - 10, start + 100).unwrap();
+    let standings: Vec<(String, u64)> = season(&deps, &env)
+        .standings
+        .into_iter()
+        .map(|standing| (standing.address, standing.points))
+        .collect();
+    assert_eq!(
+        standings,
+        vec![
+            (BOB.to_string(), 4),
+            (ALICE.to_string(), 3),
+            (CAROL.to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn the_season_prize_is_split_by_points_once_it_ends() {
+    let (mut deps, mut env, start) = setup();
+    set_season(&mut deps, &env, start - 10, start + 100).unwrap();
+    let settle = ExecuteMsg::SettleSeason {
+        season_id: 1,
+        dry_run: None,
+    };
+    assert!(matches!(
+        owner_execute(&mut deps, &env, settle.clone()),
+        Err(ContractError::SeasonNotEnded {})
+    ));
+
+    env.block.time = env.block.time.plus_seconds(100);
+    let response = owner_execute(&mut deps, &env, settle.clone()).unwrap();
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched.len(), 1);
+    assert_eq!(dispatched[0].chain, Chain::Arb);
+    let dispatch_id = dispatched[0].dispatch_id;
+    let dispatch: DispatchResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch { dispatch_id },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let decoded: DecodePayloadResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::DecodePayload {
+                chain: Chain::Arb,
+                payload: dispatch.payload,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let payouts: Vec<(String, Uint256)> = decoded
+        .winner_infos
+        .unwrap()
+        .into_iter()
+        .map(|winner_info| (winner_info.winner, winner_info.claimable_amount))
+        .collect();
+    assert_eq!(
+        payouts,
+        vec![
+            (BOB.to_string(), Uint256::from(400u32)),
+            (ALICE.to_string(), Uint256::from(300u32)),
+            (CAROL.to_string(), Uint256::from(100u32)),
+        ]
+    );
+    let season = season(&deps, &env);
+    assert!(season.settled_at.is_some());
+    assert_eq!(season.payout_dispatches, vec![dispatch_id]);
+
+    assert!(matches!(
+        owner_execute(&mut deps, &env, settle),
+        Err(ContractError::SeasonSettled {})
+    ));
+}
+
+#[test]
+fn dry_run_leaves_the_season_unsettled() {
+    let (mut deps, mut env, start) = setup();
+    set_season(&mut deps, &env, start - 10, start + 100).unwrap();
+    env.block.time = env.block.time.plus_seconds(100);
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SettleSeason {
+            season_id: 1,
+            dry_run: Some(true),
+        },
+    )
+    .unwrap();
+    assert!(response.messages.is_empty());
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched.len(), 1);
+    assert!(season(&deps, &env).settled_at.is_none());
+}
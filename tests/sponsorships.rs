@@ -0,0 +1,268 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{
+    from_json, to_json_binary, CosmosMsg, Decimal, Env, MemoryStorage, OwnedDeps, Response,
+    Uint128, Uint256, WasmMsg,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, Cw20HookMsg, DispatchData, EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg, QueryMsg,
+    RetryPolicy, Role, SponsorBalance, SponsorBalancesResponse, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const TOKEN: &str = "cw20token";
+const WINNER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+/// Contract with an active `arb` epoch 1 funded by `alice` and `bob`.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let owner = mock_info("owner", &[]);
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        owner.clone(),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        owner,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    for (sponsor, amount) in [("alice", 300u32), ("bob", 200), ("alice", 100)] {
+        fund(&mut deps, &env, sponsor, amount).unwrap();
+    }
+    (deps, env)
+}
+
+fn fund(
+    deps: &mut Deps,
+    env: &Env,
+    sponsor: &str,
+    amount: u32,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(TOKEN, &[]),
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: sponsor.to_string(),
+            amount: Uint128::from(amount),
+            msg: to_json_binary(&Cw20HookMsg::FundEpoch {
+                epoch_id: Uint256::one(),
+                chain: None,
+            })
+            .unwrap(),
+        }),
+    )
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn balances(deps: &Deps, env: &Env, sponsor: Option<&str>) -> SponsorBalancesResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetSponsorBalances {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+                sponsor: sponsor.map(str::to_string),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn balance(sponsor: &str, amount: u32) -> SponsorBalance {
+    SponsorBalance {
+        sponsor: sponsor.to_string(),
+        token: TOKEN.to_string(),
+        amount: Uint128::from(amount),
+    }
+}
+
+fn transfer(recipient: &str, amount: u32) -> CosmosMsg<PalomaMsg> {
+    CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: TOKEN.to_string(),
+        msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: recipient.to_string(),
+            amount: Uint128::from(amount),
+        })
+        .unwrap(),
+        funds: vec![],
+    })
+}
+
+#[test]
+fn contributions_are_tracked_per_sponsor() {
+    let (mut deps, env) = setup();
+    let all = balances(&deps, &env, None);
+    assert!(!all.cancelled);
+    assert_eq!(
+        all.balances,
+        vec![balance("alice", 400), balance("bob", 200)]
+    );
+    assert_eq!(
+        balances(&deps, &env, Some("bob")).balances,
+        vec![balance("bob", 200)]
+    );
+
+    assert!(matches!(
+        fund(&mut deps, &env, "carol", 0),
+        Err(ContractError::InvalidFunds {})
+    ));
+}
+
+#[test]
+fn sponsors_are_refunded_once_the_epoch_is_cancelled() {
+    let (mut deps, env) = setup();
+    let refund = |deps: &mut Deps, sponsor: &str| {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(sponsor, &[]),
+            ExecuteMsg::RefundSponsorship {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+                token: TOKEN.to_string(),
+            },
+        )
+    };
+    assert!(matches!(
+        refund(&mut deps, "alice"),
+        Err(ContractError::EpochNotCancelled {})
+    ));
+
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::CancelEpoch {
+            chain: Chain::Arb,
+            epoch_id: Uint256::one(),
+            reason: "oracle outage".to_string(),
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        fund(&mut deps, &env, "carol", 50),
+        Err(ContractError::EpochCancelled {})
+    ));
+    let response = refund(&mut deps, "alice").unwrap();
+    assert_eq!(response.messages[0].msg, transfer("alice", 400));
+    assert!(matches!(
+        refund(&mut deps, "alice"),
+        Err(ContractError::NothingToRefund {})
+    ));
+
+    let remaining = balances(&deps, &env, None);
+    assert!(remaining.cancelled);
+    assert_eq!(remaining.balances, vec![balance("bob", 200)]);
+}
+
+#[test]
+fn paid_epoch_contributions_are_swept_by_the_owner() {
+    let (mut deps, mut env) = setup();
+    let sweep = |deps: &mut Deps, env: &Env, sender: &str| {
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(sender, &[]),
+            ExecuteMsg::SweepSponsorships {
+                chain: Chain::Arb,
+                epoch_id: Uint256::one(),
+                token: TOKEN.to_string(),
+                recipient: "treasury".to_string(),
+            },
+        )
+    };
+    assert!(matches!(
+        sweep(&mut deps, &env, "owner"),
+        Err(ContractError::EpochNotPaid {})
+    ));
+
+    env.block.time = env.block.time.plus_seconds(20);
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::GrantRole {
+            role: Role::Relayer,
+            address: "relayer".to_string(),
+        },
+    )
+    .unwrap();
+    let response = owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetWinnerList {
+            epoch_id: Uint256::one(),
+            winner_infos: vec![WinnerInfo {
+                winner: WINNER.to_string(),
+                claimable_amount: Uint256::from(1_000u32),
+                chain: Some(Chain::Arb),
+            }],
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("relayer", &[]),
+        ExecuteMsg::ReportExecution {
+            dispatch_id: dispatched[0].dispatch_id,
+            evm_tx_hash: "0x01".to_string(),
+            success: true,
+            error: None,
+        },
+    )
+    .unwrap();
+
+    assert!(matches!(
+        sweep(&mut deps, &env, "alice"),
+        Err(ContractError::Unauthorized {})
+    ));
+    let response = sweep(&mut deps, &env, "owner").unwrap();
+    assert_eq!(response.messages[0].msg, transfer("treasury", 600));
+    assert!(balances(&deps, &env, None).balances.is_empty());
+}
@@ -0,0 +1,194 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response, Uint256};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    DispatchData, EpochInfo, ExecuteMsg, InstantiateMsg, PalomaMsg, QueryMsg, RetryPolicy,
+    StagedWinnerListResponse, WinnerInfo,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const ALICE: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+const BOB: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+
+/// Contract with a closed `arb` epoch 1 and an upload begun for it.
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    let now = env.block.time.seconds();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::SetActiveEpoch {
+            epoch_info: EpochInfo {
+                epoch_id: Uint256::one(),
+                competition_start: Uint256::from(now - 10),
+                competition_end: Uint256::from(now + 10),
+                entry_cnt: Uint256::one(),
+                prize_amount: Uint256::from(1_000u32),
+            },
+            signature: None,
+            dry_run: None,
+        },
+    )
+    .unwrap();
+    env.block.time = env.block.time.plus_seconds(20);
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::BeginWinnerList {
+            epoch_id: Uint256::one(),
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn append(deps: &mut Deps, env: &Env, winner: &str, amount: u32) {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::AppendWinners {
+            epoch_id: Uint256::one(),
+            winners: vec![WinnerInfo {
+                winner: winner.to_string(),
+                claimable_amount: Uint256::from(amount),
+                chain: None,
+            }],
+        },
+    )
+    .unwrap();
+}
+
+fn finalize(
+    deps: &mut Deps,
+    env: &Env,
+    expected_count: u32,
+    expected_total: u32,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::FinalizeWinnerList {
+            epoch_id: Uint256::one(),
+            expected_count,
+            expected_total: Uint256::from(expected_total),
+            dry_run: None,
+        },
+    )
+}
+
+fn staged(deps: &Deps, env: &Env) -> StagedWinnerListResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetStagedWinnerList {
+                epoch_id: Uint256::one(),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn winners_uploaded_in_parts_are_sent_at_finalize() {
+    let (mut deps, env) = setup();
+    assert!(matches!(
+        owner_execute(
+            &mut deps,
+            &env,
+            ExecuteMsg::BeginWinnerList {
+                epoch_id: Uint256::one(),
+            },
+        ),
+        Err(ContractError::StagingExists {})
+    ));
+    append(&mut deps, &env, ALICE, 30);
+    append(&mut deps, &env, BOB, 20);
+    let staging = staged(&deps, &env);
+    assert_eq!(staging.count, Some(2));
+    assert_eq!(staging.total, Some(Uint256::from(50u32)));
+
+    let response = finalize(&mut deps, &env, 2, 50).unwrap();
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched[0].dispatched, vec![ALICE, BOB]);
+    assert_eq!(staged(&deps, &env).count, None);
+}
+
+#[test]
+fn finalize_checks_the_expected_count_and_total() {
+    let (mut deps, env) = setup();
+    append(&mut deps, &env, ALICE, 30);
+    for (expected_count, expected_total) in [(2, 30), (1, 31)] {
+        assert!(matches!(
+            finalize(&mut deps, &env, expected_count, expected_total),
+            Err(ContractError::StagingMismatch { count: 1, .. })
+        ));
+    }
+    // The upload can still be completed.
+    append(&mut deps, &env, BOB, 1);
+    finalize(&mut deps, &env, 2, 31).unwrap();
+}
+
+#[test]
+fn cancelled_uploads_are_discarded() {
+    let (mut deps, env) = setup();
+    append(&mut deps, &env, ALICE, 30);
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::CancelWinnerList {
+            epoch_id: Uint256::one(),
+        },
+    )
+    .unwrap();
+    assert_eq!(staged(&deps, &env).count, None);
+    assert!(matches!(
+        finalize(&mut deps, &env, 1, 30),
+        Err(ContractError::StagingNotFound {})
+    ));
+
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::BeginWinnerList {
+            epoch_id: Uint256::one(),
+        },
+    )
+    .unwrap();
+    append(&mut deps, &env, BOB, 20);
+    let response = finalize(&mut deps, &env, 1, 20).unwrap();
+    let dispatched: Vec<DispatchData> = from_json(response.data.unwrap()).unwrap();
+    assert_eq!(dispatched[0].dispatched, vec![BOB]);
+}
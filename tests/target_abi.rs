@@ -0,0 +1,165 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier};
+use cosmwasm_std::{from_json, Decimal, Env, MemoryStorage, OwnedDeps, Response};
+use juice_bot_eth_predictor_cw::contract::{execute, instantiate, query};
+use juice_bot_eth_predictor_cw::msg::{
+    Chain, DecodePayloadResponse, DispatchData, DispatchResponse, ExecuteMsg, InstantiateMsg,
+    PalomaMsg, QueryMsg, RetryPolicy, TargetAbiResponse,
+};
+use juice_bot_eth_predictor_cw::ContractError;
+
+type Deps = OwnedDeps<MemoryStorage, MockApi, MockQuerier>;
+
+const ABI: &str = r#"[
+    {"type": "function", "name": "set_paloma", "inputs": [], "outputs": [], "stateMutability": "nonpayable"},
+    {"type": "function", "name": "set_entry_fee", "inputs": [{"name": "_fee", "type": "uint256"}], "outputs": [], "stateMutability": "nonpayable"}
+]"#;
+
+fn setup() -> (Deps, Env) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("owner", &[]),
+        InstantiateMsg {
+            retry_policy: RetryPolicy {
+                base_delay: 60,
+                multiplier: Decimal::one(),
+                max_delay: 60,
+                max_attempts: 3,
+            },
+            rollback_on_submit_failure: None,
+            paloma_msg_version: None,
+            job_arb_id: "arb-job".to_string(),
+            job_eth_id: "eth-job".to_string(),
+            creator: "paloma1creator".to_string(),
+            signers: vec![],
+        },
+    )
+    .unwrap();
+    (deps, env)
+}
+
+fn owner_execute(
+    deps: &mut Deps,
+    env: &Env,
+    msg: ExecuteMsg,
+) -> Result<Response<PalomaMsg>, ContractError> {
+    execute(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg)
+}
+
+fn set_target_abi(deps: &mut Deps, env: &Env, abi: &str) -> Result<(), ContractError> {
+    owner_execute(
+        deps,
+        env,
+        ExecuteMsg::SetTargetAbi {
+            chain: Chain::Arb,
+            abi: abi.to_string(),
+        },
+    )
+    .map(|_| ())
+}
+
+fn target_abi(deps: &Deps, env: &Env) -> TargetAbiResponse {
+    from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetTargetAbi { chain: Chain::Arb },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn calls_are_encoded_from_the_registered_abi() {
+    let (mut deps, env) = setup();
+    set_target_abi(&mut deps, &env, ABI).unwrap();
+    let abi = target_abi(&deps, &env);
+    assert_eq!(abi.abi.as_deref(), Some(ABI));
+    assert_eq!(
+        abi.functions,
+        vec!["set_entry_fee(uint256)", "set_paloma()"]
+    );
+
+    let response =
+        owner_execute(&mut deps, &env, ExecuteMsg::SetArbPaloma { dry_run: None }).unwrap();
+    let data: DispatchData = from_json(response.data.unwrap()).unwrap();
+    let dispatch: DispatchResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetDispatch {
+                dispatch_id: data.dispatch_id,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let decoded: DecodePayloadResponse = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::DecodePayload {
+                chain: Chain::Arb,
+                payload: dispatch.payload,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(decoded.function, "set_paloma()");
+
+    // The registered ABI has no `update_compass`.
+    assert!(matches!(
+        owner_execute(
+            &mut deps,
+            &env,
+            ExecuteMsg::UpdateArbCompass {
+                new_compass: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+                dry_run: None,
+            },
+        ),
+        Err(ContractError::FunctionNotFound { .. })
+    ));
+}
+
+#[test]
+fn clearing_the_abi_restores_the_builtin_functions() {
+    let (mut deps, env) = setup();
+    let builtin = target_abi(&deps, &env);
+    assert_eq!(builtin.abi, None);
+    set_target_abi(&mut deps, &env, ABI).unwrap();
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::ClearTargetAbi { chain: Chain::Arb },
+    )
+    .unwrap();
+    assert_eq!(target_abi(&deps, &env).functions, builtin.functions);
+    owner_execute(
+        &mut deps,
+        &env,
+        ExecuteMsg::UpdateArbCompass {
+            new_compass: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            dry_run: None,
+        },
+    )
+    .unwrap();
+}
+
+#[test]
+fn invalid_abis_are_rejected() {
+    let (mut deps, env) = setup();
+    for abi in [
+        "not json",
+        r#"[{"type": "function", "name": "f", "inputs": [{"name": "x", "type": "uint7"}], "outputs": [], "stateMutability": "nonpayable"}]"#,
+    ] {
+        assert!(matches!(
+            set_target_abi(&mut deps, &env, abi),
+            Err(ContractError::InvalidAbi { .. })
+        ));
+    }
+    assert_eq!(target_abi(&deps, &env).abi, None);
+}